@@ -1,13 +1,19 @@
 //! Information about the material's nature.
 //! Mostly fills field 6.
 
+use crate::blank_as_hash;
+use crate::error::{InvalidCode, ParseCodeError};
 use num_enum::FromPrimitive;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
 
 /// Extra information about the physical characteristics of the material.
 ///
 /// Original documentation:
 ///
 /// <https://www.loc.gov/marc/bibliographic/concise/bd006.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum AdditionalMaterialCharacteristics {
 	/// A book or other text.
 	Book {
@@ -74,6 +80,10 @@ pub enum AdditionalMaterialCharacteristics {
 		/// The projection of the map.
 		/// Position 5-6.
 		projection: Projection,
+		/// The projection code as it appears in the record, e.g. `*b"bd"`.
+		/// Kept because a half-filled code like `a|` decodes to `Projection::NotCoded` but still says something.
+		/// Position 5-6.
+		projection_code: [u8; 2],
 		/// The type of map.
 		/// Position 8.
 		cartographic_type: CartographicType,
@@ -112,6 +122,10 @@ pub enum AdditionalMaterialCharacteristics {
 		/// Form of composition.
 		/// Position 1-2.
 		form_of_composition: FormOfComposition,
+		/// The form of composition code as it appears in the record, e.g. `*b"sg"`.
+		/// Kept because a half-filled code like `a|` decodes to `FormOfComposition::NotCoded` but still says something.
+		/// Position 1-2.
+		form_of_composition_code: [u8; 2],
 		/// Format of the music or sound recording.
 		/// Position 3.
 		format_of_music: FormatOfMusic,
@@ -166,9 +180,507 @@ pub enum AdditionalMaterialCharacteristics {
 	},
 }
 
+impl AdditionalMaterialCharacteristics {
+	/// Decodes the 18 bytes of a field 006.
+	///
+	/// Position 0 selects the variant:
+	/// `a`/`t` are books, `m` computer files, `e`/`f` maps, `p` mixed materials,
+	/// `c`/`d`/`i`/`j` music and `s` continuing resources.
+	/// Returns `None` if the field is empty or position 0 is not one of those codes
+	/// (visual materials, `g`/`k`/`o`/`r`, aren't modelled yet).
+	///
+	/// Positions past the end of a short field decode to each enum's `NotCoded` default.
+	/// Blanks are read as `#`, which is how the MARC documentation (and these enums) write them.
+	/// Two-byte codes are also kept as they appear, with missing positions as `|`,
+	/// since half-filled ones like `a|` or ` a` match no variant.
+	pub fn from_field_006(bytes: &[u8]) -> Option<Self> {
+		let mut raw = [b'|'; 18];
+		for (position, &byte) in raw.iter_mut().zip(bytes) {
+			*position = byte;
+		}
+		let bytes = raw.map(blank_as_hash);
+		let pair = |i: usize| [bytes[i], bytes[i + 1]];
+		let raw_pair = |i: usize| [raw[i], raw[i + 1]];
+		Some(match bytes[0] {
+			b'a' | b't' => AdditionalMaterialCharacteristics::Book {
+				manuscript: bytes[0] == b't',
+				illustrations: std::array::from_fn(|i| Illustration::from_primitive(bytes[1 + i])),
+				target_audience: TargetAudience::from_primitive(bytes[5]),
+				form_of_item: FormOfItem::from_primitive(bytes[6]),
+				nature_of_contents: std::array::from_fn(|i| {
+					NatureOfContents::from_primitive(bytes[7 + i])
+				}),
+				government_publication: GovernmentPublication::from_primitive(bytes[11]),
+				conference_publication: ConferencePublication::from_primitive(bytes[12]),
+				festschrift: Festschrift::from_primitive(bytes[13]),
+				index: Index::from_primitive(bytes[14]),
+				literary_form: LiteraryForm::from_primitive(bytes[16]),
+				biography: Biography::from_primitive(bytes[17]),
+			},
+			b'm' => AdditionalMaterialCharacteristics::ComputerFile {
+				target_audience: TargetAudience::from_primitive(bytes[5]),
+				form_of_item: FormOfItem::from_primitive(bytes[6]),
+				file_type: FileType::from_primitive(bytes[9]),
+				government_publication: GovernmentPublication::from_primitive(bytes[11]),
+			},
+			b'e' | b'f' => AdditionalMaterialCharacteristics::Map {
+				manuscript: bytes[0] == b'f',
+				relief: std::array::from_fn(|i| Relief::from_primitive(bytes[1 + i])),
+				projection: Projection::from_code(pair(5)),
+				projection_code: raw_pair(5),
+				cartographic_type: CartographicType::from_primitive(bytes[8]),
+				government_publication: GovernmentPublication::from_primitive(bytes[11]),
+				form_of_item: FormOfItem::from_primitive(bytes[12]),
+				index: Index::from_primitive(bytes[14]),
+				special_format_characteristics: std::array::from_fn(|i| {
+					SpecialFormatCharacteristics::from_primitive(bytes[16 + i])
+				}),
+			},
+			b'p' => AdditionalMaterialCharacteristics::MixedMaterials {
+				form_of_item: FormOfItem::from_primitive(bytes[6]),
+			},
+			b'c' | b'd' | b'i' | b'j' => AdditionalMaterialCharacteristics::Music {
+				recorded: matches!(bytes[0], b'c' | b'd'),
+				manuscript: bytes[0] == b'd',
+				musical: bytes[0] != b'i',
+				form_of_composition: FormOfComposition::from_code(pair(1)),
+				form_of_composition_code: raw_pair(1),
+				format_of_music: FormatOfMusic::from_primitive(bytes[3]),
+				parts: MusicParts::from_primitive(bytes[4]),
+				target_audience: TargetAudience::from_primitive(bytes[5]),
+				form_of_item: FormOfItem::from_primitive(bytes[6]),
+				accompanying_matter: std::array::from_fn(|i| {
+					AccompanyingMatter::from_primitive(bytes[7 + i])
+				}),
+				literary_text: std::array::from_fn(|i| MusicText::from_primitive(bytes[13 + i])),
+				transposition_and_arrangement: TranspositionArrangement::from_primitive(bytes[16]),
+			},
+			b's' => AdditionalMaterialCharacteristics::ContinuingResources {
+				frequency: Frequency::from_primitive(bytes[1]),
+				regularity: Regularity::from_primitive(bytes[2]),
+				form_of_original: FormOfItem::from_primitive(bytes[5]),
+				form_of_current: FormOfItem::from_primitive(bytes[6]),
+				nature_of_work: NatureOfContents::from_primitive(bytes[7]),
+				nature_of_content: std::array::from_fn(|i| {
+					NatureOfContents::from_primitive(bytes[8 + i])
+				}),
+				government_publication: GovernmentPublication::from_primitive(bytes[11]),
+				conference_publication: ConferencePublication::from_primitive(bytes[12]),
+				original_alphabet_or_script: AlphabetScript::from_primitive(bytes[16]),
+			},
+			_ => return None,
+		})
+	}
+
+	/// Encodes the values back into the 18 bytes of a field 006; the inverse of [`from_field_006`](Self::from_field_006).
+	///
+	/// `#` codes are written as the blanks they stand for, and positions the variant doesn't model are written as blanks too:
+	/// the undefined ones, and the few defined ones that aren't read yet, such as a continuing resource's type.
+	/// A `NotCoded` two-byte code is written from its raw code, so half-filled codes like `a|` survive the round trip.
+	pub fn to_field_006(&self) -> [u8; 18] {
+		let mut bytes = [b'#'; 18];
+		let mut put = |start: usize, codes: &[u8]| {
+			bytes[start..start + codes.len()].copy_from_slice(codes);
+		};
+		put(0, &[self.type_of_material()]);
+		match self {
+			AdditionalMaterialCharacteristics::Book {
+				manuscript: _,
+				illustrations,
+				target_audience,
+				form_of_item,
+				nature_of_contents,
+				government_publication,
+				conference_publication,
+				festschrift,
+				index,
+				literary_form,
+				biography,
+			} => {
+				put(1, &illustrations.map(Illustration::code));
+				put(5, &[target_audience.code(), form_of_item.code()]);
+				put(7, &nature_of_contents.map(NatureOfContents::code));
+				put(
+					11,
+					&[
+						government_publication.code(),
+						conference_publication.code(),
+						festschrift.code(),
+						index.code(),
+					],
+				);
+				put(16, &[literary_form.code(), biography.code()]);
+			}
+			AdditionalMaterialCharacteristics::ComputerFile {
+				target_audience,
+				form_of_item,
+				file_type,
+				government_publication,
+			} => {
+				put(5, &[target_audience.code(), form_of_item.code()]);
+				put(9, &[file_type.code()]);
+				put(11, &[government_publication.code()]);
+			}
+			AdditionalMaterialCharacteristics::Map {
+				manuscript: _,
+				relief,
+				projection,
+				projection_code,
+				cartographic_type,
+				government_publication,
+				form_of_item,
+				index,
+				special_format_characteristics,
+			} => {
+				put(1, &relief.map(Relief::code));
+				put(
+					5,
+					&match projection {
+						Projection::NotCoded => *projection_code,
+						projection => projection.code(),
+					},
+				);
+				put(8, &[cartographic_type.code()]);
+				put(11, &[government_publication.code(), form_of_item.code()]);
+				put(14, &[index.code()]);
+				put(
+					16,
+					&special_format_characteristics.map(SpecialFormatCharacteristics::code),
+				);
+			}
+			AdditionalMaterialCharacteristics::MixedMaterials { form_of_item } => {
+				put(6, &[form_of_item.code()]);
+			}
+			AdditionalMaterialCharacteristics::Music {
+				recorded: _,
+				manuscript: _,
+				musical: _,
+				form_of_composition,
+				form_of_composition_code,
+				format_of_music,
+				parts,
+				target_audience,
+				form_of_item,
+				accompanying_matter,
+				literary_text,
+				transposition_and_arrangement,
+			} => {
+				put(
+					1,
+					&match form_of_composition {
+						FormOfComposition::NotCoded => *form_of_composition_code,
+						form_of_composition => form_of_composition.code(),
+					},
+				);
+				put(
+					3,
+					&[
+						format_of_music.code(),
+						parts.code(),
+						target_audience.code(),
+						form_of_item.code(),
+					],
+				);
+				put(7, &accompanying_matter.map(AccompanyingMatter::code));
+				put(13, &literary_text.map(MusicText::code));
+				put(16, &[transposition_and_arrangement.code()]);
+			}
+			AdditionalMaterialCharacteristics::ContinuingResources {
+				frequency,
+				regularity,
+				form_of_original,
+				form_of_current,
+				nature_of_work,
+				nature_of_content,
+				government_publication,
+				conference_publication,
+				original_alphabet_or_script,
+			} => {
+				put(1, &[frequency.code(), regularity.code()]);
+				put(
+					5,
+					&[
+						form_of_original.code(),
+						form_of_current.code(),
+						nature_of_work.code(),
+					],
+				);
+				put(8, &nature_of_content.map(NatureOfContents::code));
+				put(
+					11,
+					&[government_publication.code(), conference_publication.code()],
+				);
+				put(16, &[original_alphabet_or_script.code()]);
+			}
+		}
+		bytes.map(|byte| if byte == b'#' { b' ' } else { byte })
+	}
+
+	/// The type of material code at position 0 of a field 006, e.g. `b't'` for a manuscript book.
+	fn type_of_material(&self) -> u8 {
+		match self {
+			AdditionalMaterialCharacteristics::Book { manuscript, .. } => {
+				if *manuscript {
+					b't'
+				} else {
+					b'a'
+				}
+			}
+			AdditionalMaterialCharacteristics::ComputerFile { .. } => b'm',
+			AdditionalMaterialCharacteristics::Map { manuscript, .. } => {
+				if *manuscript {
+					b'f'
+				} else {
+					b'e'
+				}
+			}
+			AdditionalMaterialCharacteristics::MixedMaterials { .. } => b'p',
+			AdditionalMaterialCharacteristics::Music {
+				recorded,
+				manuscript,
+				musical,
+				..
+			} => match (recorded, manuscript, musical) {
+				(true, true, _) => b'd',
+				(true, false, _) => b'c',
+				(false, _, true) => b'j',
+				(false, _, false) => b'i',
+			},
+			AdditionalMaterialCharacteristics::ContinuingResources { .. } => b's',
+		}
+	}
+
+	/// The form of item.
+	///
+	/// Unlike the other `_or_default` accessors this never falls back, since every variant carries a form of item;
+	/// for `ContinuingResources` it's the form of the current item rather than of the original.
+	/// It's only `NotCoded` when the record says so.
+	pub fn form_of_item_or_default(&self) -> FormOfItem {
+		match self {
+			AdditionalMaterialCharacteristics::Book { form_of_item, .. }
+			| AdditionalMaterialCharacteristics::ComputerFile { form_of_item, .. }
+			| AdditionalMaterialCharacteristics::Map { form_of_item, .. }
+			| AdditionalMaterialCharacteristics::MixedMaterials { form_of_item }
+			| AdditionalMaterialCharacteristics::Music { form_of_item, .. } => *form_of_item,
+			AdditionalMaterialCharacteristics::ContinuingResources {
+				form_of_current, ..
+			} => *form_of_current,
+		}
+	}
+
+	/// The target audience, or `TargetAudience::NotCoded` if the variant doesn't carry one.
+	///
+	/// Carried by `Book`, `ComputerFile` and `Music`.
+	pub fn target_audience_or_default(&self) -> TargetAudience {
+		match self {
+			AdditionalMaterialCharacteristics::Book {
+				target_audience, ..
+			}
+			| AdditionalMaterialCharacteristics::ComputerFile {
+				target_audience, ..
+			}
+			| AdditionalMaterialCharacteristics::Music {
+				target_audience, ..
+			} => *target_audience,
+			_ => TargetAudience::default(),
+		}
+	}
+
+	/// The type of government publication, or `GovernmentPublication::NotCoded` if the variant doesn't carry one.
+	///
+	/// Carried by `Book`, `ComputerFile`, `Map` and `ContinuingResources`.
+	pub fn government_publication_or_default(&self) -> GovernmentPublication {
+		match self {
+			AdditionalMaterialCharacteristics::Book {
+				government_publication,
+				..
+			}
+			| AdditionalMaterialCharacteristics::ComputerFile {
+				government_publication,
+				..
+			}
+			| AdditionalMaterialCharacteristics::Map {
+				government_publication,
+				..
+			}
+			| AdditionalMaterialCharacteristics::ContinuingResources {
+				government_publication,
+				..
+			} => *government_publication,
+			_ => GovernmentPublication::default(),
+		}
+	}
+
+	/// Whether this is a conference publication, or `ConferencePublication::NotCoded` if the variant doesn't say.
+	///
+	/// Carried by `Book` and `ContinuingResources`.
+	pub fn conference_publication_or_default(&self) -> ConferencePublication {
+		match self {
+			AdditionalMaterialCharacteristics::Book {
+				conference_publication,
+				..
+			}
+			| AdditionalMaterialCharacteristics::ContinuingResources {
+				conference_publication,
+				..
+			} => *conference_publication,
+			_ => ConferencePublication::default(),
+		}
+	}
+
+	/// Whether an index is included, or `Index::NotCoded` if the variant doesn't say.
+	///
+	/// Carried by `Book` and `Map`.
+	pub fn index_or_default(&self) -> Index {
+		match self {
+			AdditionalMaterialCharacteristics::Book { index, .. }
+			| AdditionalMaterialCharacteristics::Map { index, .. } => *index,
+			_ => Index::default(),
+		}
+	}
+
+	/// Describes each decoded value alongside the 006 position(s) it came from,
+	/// e.g. `[0]=a manuscript=false [1-4]=illustrations: Maps,None,None,None ...`.
+	/// Handy for tracking down position-mapping mistakes, which the derived `Debug` of the inner enums hides.
+	pub fn debug_positions(&self) -> String {
+		fn list<T: std::fmt::Debug>(values: &[T]) -> String {
+			values
+				.iter()
+				.map(|value| format!("{:?}", value))
+				.collect::<Vec<_>>()
+				.join(",")
+		}
+		match self {
+			AdditionalMaterialCharacteristics::Book {
+				manuscript,
+				illustrations,
+				target_audience,
+				form_of_item,
+				nature_of_contents,
+				government_publication,
+				conference_publication,
+				festschrift,
+				index,
+				literary_form,
+				biography,
+			} => format!(
+				"[0]={} manuscript={} [1-4]=illustrations: {} [5]=target_audience: {:?} [6]=form_of_item: {:?} [7-10]=nature_of_contents: {} [11]=government_publication: {:?} [12]=conference_publication: {:?} [13]=festschrift: {:?} [14]=index: {:?} [16]=literary_form: {:?} [17]=biography: {:?}",
+				self.type_of_material() as char,
+				manuscript,
+				list(illustrations),
+				target_audience,
+				form_of_item,
+				list(nature_of_contents),
+				government_publication,
+				conference_publication,
+				festschrift,
+				index,
+				literary_form,
+				biography,
+			),
+			AdditionalMaterialCharacteristics::ComputerFile {
+				target_audience,
+				form_of_item,
+				file_type,
+				government_publication,
+			} => format!(
+				"[0]=m [5]=target_audience: {:?} [6]=form_of_item: {:?} [9]=file_type: {:?} [11]=government_publication: {:?}",
+				target_audience, form_of_item, file_type, government_publication,
+			),
+			AdditionalMaterialCharacteristics::Map {
+				manuscript,
+				relief,
+				projection,
+				projection_code: _,
+				cartographic_type,
+				government_publication,
+				form_of_item,
+				index,
+				special_format_characteristics,
+			} => format!(
+				"[0]={} manuscript={} [1-4]=relief: {} [5-6]=projection: {:?} [8]=cartographic_type: {:?} [11]=government_publication: {:?} [12]=form_of_item: {:?} [14]=index: {:?} [16-17]=special_format_characteristics: {}",
+				self.type_of_material() as char,
+				manuscript,
+				list(relief),
+				projection,
+				cartographic_type,
+				government_publication,
+				form_of_item,
+				index,
+				list(special_format_characteristics),
+			),
+			AdditionalMaterialCharacteristics::MixedMaterials { form_of_item } => {
+				format!("[0]=p [6]=form_of_item: {:?}", form_of_item)
+			}
+			AdditionalMaterialCharacteristics::Music {
+				recorded,
+				manuscript,
+				musical,
+				form_of_composition,
+				form_of_composition_code: _,
+				format_of_music,
+				parts,
+				target_audience,
+				form_of_item,
+				accompanying_matter,
+				literary_text,
+				transposition_and_arrangement,
+			} => format!(
+				"[0]={} recorded={} manuscript={} musical={} [1-2]=form_of_composition: {:?} [3]=format_of_music: {:?} [4]=parts: {:?} [5]=target_audience: {:?} [6]=form_of_item: {:?} [7-12]=accompanying_matter: {} [13-14]=literary_text: {} [16]=transposition_and_arrangement: {:?}",
+				self.type_of_material() as char,
+				recorded,
+				manuscript,
+				musical,
+				form_of_composition,
+				format_of_music,
+				parts,
+				target_audience,
+				form_of_item,
+				list(accompanying_matter),
+				list(literary_text),
+				transposition_and_arrangement,
+			),
+			AdditionalMaterialCharacteristics::ContinuingResources {
+				frequency,
+				regularity,
+				form_of_original,
+				form_of_current,
+				nature_of_work,
+				nature_of_content,
+				government_publication,
+				conference_publication,
+				original_alphabet_or_script,
+			} => format!(
+				"[0]=s [1]=frequency: {:?} [2]=regularity: {:?} [5]=form_of_original: {:?} [6]=form_of_current: {:?} [7]=nature_of_work: {:?} [8-10]=nature_of_content: {} [11]=government_publication: {:?} [12]=conference_publication: {:?} [16]=original_alphabet_or_script: {:?}",
+				frequency,
+				regularity,
+				form_of_original,
+				form_of_current,
+				nature_of_work,
+				list(nature_of_content),
+				government_publication,
+				conference_publication,
+				original_alphabet_or_script,
+			),
+		}
+	}
+}
+
+impl TryFrom<&[u8; 18]> for AdditionalMaterialCharacteristics {
+	type Error = InvalidCode;
+
+	/// Decodes a whole field 006 like [`from_field_006`](AdditionalMaterialCharacteristics::from_field_006),
+	/// with the length checked at compile time.
+	/// Fails with the position 0 code if it isn't one of the modelled types of material.
+	fn try_from(bytes: &[u8; 18]) -> Result<Self, Self::Error> {
+		AdditionalMaterialCharacteristics::from_field_006(bytes).ok_or(InvalidCode(bytes[0]))
+	}
+}
+
 /// The types of illustrations the book has.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum Illustration {
 	/// The book has no more illustrations.
 	None = b'#',
@@ -207,9 +719,35 @@ pub enum Illustration {
 	NotCoded = b'|',
 }
 
+impl Illustration {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			Illustration::None => "No illustrations",
+			Illustration::Some => "Illustrations",
+			Illustration::Maps => "Maps",
+			Illustration::Portraits => "Portraits",
+			Illustration::Charts => "Charts",
+			Illustration::Plans => "Plans",
+			Illustration::Plates => "Plates",
+			Illustration::Music => "Music",
+			Illustration::Facsimiles => "Facsimiles",
+			Illustration::CoatsOfArms => "Coats of arms",
+			Illustration::GenealogicalTables => "Genealogical tables",
+			Illustration::Forms => "Forms",
+			Illustration::Samples => "Samples",
+			Illustration::Phonodiscs => "Phonodisc, phonowire, etc.",
+			Illustration::Photographs => "Photographs",
+			Illustration::Illuminations => "Illuminations",
+			Illustration::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The book's target audience.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum TargetAudience {
 	/// The book's target audience is unknown.
 	Unknown = b'#',
@@ -234,9 +772,28 @@ pub enum TargetAudience {
 	NotCoded = b'|',
 }
 
+impl TargetAudience {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			TargetAudience::Unknown => "Unknown or not specified",
+			TargetAudience::Preschool => "Preschool",
+			TargetAudience::Primary => "Primary",
+			TargetAudience::PreAdolescent => "Preadolescent",
+			TargetAudience::Adolescent => "Adolescent",
+			TargetAudience::Adult => "Adult",
+			TargetAudience::Specialized => "Specialized",
+			TargetAudience::General => "General",
+			TargetAudience::Juvenile => "Juvenile",
+			TargetAudience::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The form in which the book is stored.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum FormOfItem {
 	/// The book is not in any of the available forms.
 	None = b'#',
@@ -266,9 +823,30 @@ pub enum FormOfItem {
 	NotCoded = b'|',
 }
 
+impl FormOfItem {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			FormOfItem::None => "None of the following",
+			FormOfItem::Microfilm => "Microfilm",
+			FormOfItem::Microfiche => "Microfiche",
+			FormOfItem::Microopaque => "Microopaque",
+			FormOfItem::LargePrint => "Large print",
+			FormOfItem::Newspaper => "Newspaper format",
+			FormOfItem::Braille => "Braille",
+			FormOfItem::Online => "Online",
+			FormOfItem::DirectElectronic => "Direct electronic",
+			FormOfItem::PrintReproduction => "Regular print reproduction",
+			FormOfItem::Electronic => "Electronic",
+			FormOfItem::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The nature of the book's contents.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum NatureOfContents {
 	/// # - No specified nature of contents
 	None = b'#',
@@ -331,9 +909,47 @@ pub enum NatureOfContents {
 	NotCoded = b'|',
 }
 
+impl NatureOfContents {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			NatureOfContents::None => "No specified nature of contents",
+			NatureOfContents::Abstracts => "Abstracts/summaries",
+			NatureOfContents::Bibliographies => "Bibliographies",
+			NatureOfContents::Catalogs => "Catalogs",
+			NatureOfContents::Dictionaries => "Dictionaries",
+			NatureOfContents::Encyclopedias => "Encyclopedias",
+			NatureOfContents::Handbooks => "Handbooks",
+			NatureOfContents::LegalArticles => "Legal articles",
+			NatureOfContents::Indexes => "Indexes",
+			NatureOfContents::PatentDocument => "Patent document",
+			NatureOfContents::Discographies => "Discographies",
+			NatureOfContents::Legislation => "Legislation",
+			NatureOfContents::Theses => "Theses",
+			NatureOfContents::SurveysOfLiterature => "Surveys of literature in a subject area",
+			NatureOfContents::Reviews => "Reviews",
+			NatureOfContents::ProgrammedTexts => "Programmed texts",
+			NatureOfContents::Filmographies => "Filmographies",
+			NatureOfContents::Directories => "Directories",
+			NatureOfContents::Statistics => "Statistics",
+			NatureOfContents::TechnicalReports => "Technical reports",
+			NatureOfContents::Standards => "Standards/specifications",
+			NatureOfContents::LegalCases => "Legal cases and case notes",
+			NatureOfContents::LawReports => "Law reports and digests",
+			NatureOfContents::Yearbooks => "Yearbooks",
+			NatureOfContents::Treaties => "Treaties",
+			NatureOfContents::Offprints => "Offprints",
+			NatureOfContents::Calendars => "Calendars",
+			NatureOfContents::Comics => "Comics/graphic novels",
+			NatureOfContents::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// What type of government publication the book is, if any.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum GovernmentPublication {
 	/// # - Not a government publication
 	None = b'#',
@@ -362,9 +978,30 @@ pub enum GovernmentPublication {
 	NotCoded = b'|',
 }
 
+impl GovernmentPublication {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			GovernmentPublication::None => "Not a government publication",
+			GovernmentPublication::Autonomous => "Autonomous or semi-autonomous component",
+			GovernmentPublication::Multilocal => "Multilocal",
+			GovernmentPublication::Federal => "Federal/national",
+			GovernmentPublication::International => "International intergovernmental",
+			GovernmentPublication::Local => "Local",
+			GovernmentPublication::Multistate => "Multistate",
+			GovernmentPublication::Undetermined => "Government publication-level undetermined",
+			GovernmentPublication::State => "State, provincial, territorial, dependent, etc.",
+			GovernmentPublication::Unknown => "Unknown if item is government publication",
+			GovernmentPublication::Other => "Other",
+			GovernmentPublication::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// Whether the book is a conference publication.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConferencePublication {
 	/// 0 - Not a conference publication
 	NonConference = b'0',
@@ -375,10 +1012,22 @@ pub enum ConferencePublication {
 	NotCoded = b'|',
 }
 
+impl ConferencePublication {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			ConferencePublication::NonConference => "Not a conference publication",
+			ConferencePublication::Conference => "Conference publication",
+			ConferencePublication::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// Whether the book is a 'festschrift'.
 /// I have no idea what this means.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum Festschrift {
 	/// 0 - Not a festschrift
 	NotFestschrift = b'0',
@@ -389,9 +1038,21 @@ pub enum Festschrift {
 	NotCoded = b'|',
 }
 
+impl Festschrift {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			Festschrift::NotFestschrift => "Not a festschrift",
+			Festschrift::Festschrift => "Festschrift",
+			Festschrift::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// Whether the book contains an index to its own contents.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum Index {
 	/// 0 - No index
 	None = b'0',
@@ -402,9 +1063,21 @@ pub enum Index {
 	NotCoded = b'|',
 }
 
+impl Index {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			Index::None => "No index",
+			Index::Index => "Index",
+			Index::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The literary form of the book.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum LiteraryForm {
 	/// 0 - Not fiction (not further specified)
 	NotFiction = b'0',
@@ -435,9 +1108,31 @@ pub enum LiteraryForm {
 	NotCoded = b'|',
 }
 
+impl LiteraryForm {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			LiteraryForm::NotFiction => "Not fiction (not further specified)",
+			LiteraryForm::Fiction => "Fiction (not further specified)",
+			LiteraryForm::Dramas => "Dramas",
+			LiteraryForm::Essays => "Essays",
+			LiteraryForm::Novels => "Novels",
+			LiteraryForm::Humor => "Humor, satires, etc.",
+			LiteraryForm::Letters => "Letters",
+			LiteraryForm::ShortStories => "Short stories",
+			LiteraryForm::MixedForms => "Mixed forms",
+			LiteraryForm::Poetry => "Poetry",
+			LiteraryForm::Speeches => "Speeches",
+			LiteraryForm::Unknown => "Unknown",
+			LiteraryForm::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The type of biography, if any.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum Biography {
 	/// # - No biographical material
 	None = b'#',
@@ -454,9 +1149,24 @@ pub enum Biography {
 	NotCoded = b'|',
 }
 
+impl Biography {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			Biography::None => "No biographical material",
+			Biography::Autobiography => "Autobiography",
+			Biography::Individual => "Individual biography",
+			Biography::Collective => "Collective biography",
+			Biography::Contains => "Contains biographical information",
+			Biography::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The type of the file.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum FileType {
 	/// a - Numeric data
 	Numeric = b'a',
@@ -489,9 +1199,32 @@ pub enum FileType {
 	NotCoded = b'|',
 }
 
+impl FileType {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			FileType::Numeric => "Numeric data",
+			FileType::ComputerProgram => "Computer program",
+			FileType::Representational => "Representational",
+			FileType::Document => "Document",
+			FileType::Bibliographic => "Bibliographic data",
+			FileType::Font => "Font",
+			FileType::Game => "Game",
+			FileType::Sound => "Sound",
+			FileType::InteractiveMultimedia => "Interactive multimedia",
+			FileType::OnlineSystem => "Online system or service",
+			FileType::Combination => "Combination",
+			FileType::Unknown => "Unknown",
+			FileType::Other => "Other",
+			FileType::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The type of relief used by the map.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum Relief {
 	/// # - No relief shown
 	None = b'#',
@@ -524,114 +1257,219 @@ pub enum Relief {
 	NotCoded = b'|',
 }
 
+impl Relief {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			Relief::None => "No relief shown",
+			Relief::Contours => "Contours",
+			Relief::Shading => "Shading",
+			Relief::Gradient => "Gradient and bathymetric tints",
+			Relief::Hachures => "Hachures",
+			Relief::Bathymetry => "Bathymetry/soundings",
+			Relief::FormLines => "Form lines",
+			Relief::SpotHeights => "Spot heights",
+			Relief::Pictorially => "Pictorially",
+			Relief::LandForms => "Land forms",
+			Relief::Isolines => "Bathymetry/isolines",
+			Relief::RockDrawings => "Rock drawings",
+			Relief::Other => "Other",
+			Relief::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The projection of the map.
 /// This is quite possibly the most disgusting enum ever made.
 /// I had to disable macro error reporting in Rust Analyzer because of this.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+///
+/// Half-filled codes like `a|` or ` a` don't match any variant, so the pair decodes to `NotCoded`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u16)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum Projection {
 	/// ## - Projection not specified
-	NotSpecified = (b"##"[0] as u16) << 8 | b"##"[1] as u16,
+	NotSpecified = pack(*b"##"),
 	/// aa - Aitoff
-	Aitoff = (b"aa"[0] as u16) << 8 | b"aa"[1] as u16,
+	Aitoff = pack(*b"aa"),
 	/// ab - Gnomic
-	Gnomic = (b"ab"[0] as u16) << 8 | b"ab"[1] as u16,
+	Gnomic = pack(*b"ab"),
 	/// ac - Lambert's azimuthal equal area
-	LambertAzimuthal = (b"ac"[0] as u16) << 8 | b"ac"[1] as u16,
+	LambertAzimuthal = pack(*b"ac"),
 	/// ad - Orthographic
-	Orthographic = (b"ad"[0] as u16) << 8 | b"ad"[1] as u16,
+	Orthographic = pack(*b"ad"),
 	/// ae - Azimuthal equidistant
-	AzimuthalEquidistant = (b"ae"[0] as u16) << 8 | b"ae"[1] as u16,
+	AzimuthalEquidistant = pack(*b"ae"),
 	/// af - Stereographic
-	Stereographic = (b"af"[0] as u16) << 8 | b"af"[1] as u16,
+	Stereographic = pack(*b"af"),
 	/// ag - General vertical near-sided
-	GeneralVerticalNearSided = (b"ag"[0] as u16) << 8 | b"ag"[1] as u16,
+	GeneralVerticalNearSided = pack(*b"ag"),
 	/// am - Modified stereographic for Alaska
-	ModifiedStereographicAlaska = (b"am"[0] as u16) << 8 | b"am"[1] as u16,
+	ModifiedStereographicAlaska = pack(*b"am"),
 	/// an - Chamberlin trimetric
-	ChamberlinTrimetric = (b"an"[0] as u16) << 8 | b"an"[1] as u16,
+	ChamberlinTrimetric = pack(*b"an"),
 	/// ap - Polar stereographic
-	PolarStereographic = (b"ap"[0] as u16) << 8 | b"ap"[1] as u16,
+	PolarStereographic = pack(*b"ap"),
 	/// au - Azimuthal, specific type unknown
-	AzimuthalUnknown = (b"au"[0] as u16) << 8 | b"au"[1] as u16,
+	AzimuthalUnknown = pack(*b"au"),
 	/// az - Azimuthal, other
-	AzimuthalOther = (b"az"[0] as u16) << 8 | b"az"[1] as u16,
+	AzimuthalOther = pack(*b"az"),
 	/// ba - Gall
-	Gall = (b"ba"[0] as u16) << 8 | b"ba"[1] as u16,
+	Gall = pack(*b"ba"),
 	/// bb - Goode's homolographic
-	GoodeHomolographic = (b"bb"[0] as u16) << 8 | b"bb"[1] as u16,
+	GoodeHomolographic = pack(*b"bb"),
 	/// bc - Lambert's cylindrical equal area
-	LambertCylindrical = (b"bc"[0] as u16) << 8 | b"bc"[1] as u16,
+	LambertCylindrical = pack(*b"bc"),
 	/// bd - Mercator
-	Mercator = (b"bd"[0] as u16) << 8 | b"bd"[1] as u16,
+	Mercator = pack(*b"bd"),
 	/// be - Miller
-	Miller = (b"be"[0] as u16) << 8 | b"be"[1] as u16,
+	Miller = pack(*b"be"),
 	/// bf - Mollweide
-	Mollweide = (b"bf"[0] as u16) << 8 | b"bf"[1] as u16,
+	Mollweide = pack(*b"bf"),
 	/// bg - Sinusoidal
-	Sinusoidal = (b"bg"[0] as u16) << 8 | b"bg"[1] as u16,
+	Sinusoidal = pack(*b"bg"),
 	/// bh - Transverse Mercator
-	TransverseMercator = (b"bh"[0] as u16) << 8 | b"bh"[1] as u16,
+	TransverseMercator = pack(*b"bh"),
 	/// bi - Gauss-Kruger
-	GaussKruger = (b"bi"[0] as u16) << 8 | b"bi"[1] as u16,
+	GaussKruger = pack(*b"bi"),
 	/// bj - Equirectangular
-	Equirectangular = (b"bj"[0] as u16) << 8 | b"bj"[1] as u16,
+	Equirectangular = pack(*b"bj"),
 	/// bk - Krovak
-	Krovak = (b"bk"[0] as u16) << 8 | b"bk"[1] as u16,
+	Krovak = pack(*b"bk"),
 	/// bl - Cassini-Soldner
-	CassiniSoldner = (b"bl"[0] as u16) << 8 | b"bl"[1] as u16,
+	CassiniSoldner = pack(*b"bl"),
 	/// bo - Oblique Mercator
-	ObliqueMercator = (b"bo"[0] as u16) << 8 | b"bo"[1] as u16,
+	ObliqueMercator = pack(*b"bo"),
 	/// br - Robinson
-	Robinson = (b"br"[0] as u16) << 8 | b"br"[1] as u16,
+	Robinson = pack(*b"br"),
 	/// bs - Space oblique Mercator
-	SpaceObliqueMercator = (b"bs"[0] as u16) << 8 | b"bs"[1] as u16,
+	SpaceObliqueMercator = pack(*b"bs"),
 	/// bu - Cylindrical, specific type unknown
-	CylindricalUnknown = (b"bu"[0] as u16) << 8 | b"bu"[1] as u16,
+	CylindricalUnknown = pack(*b"bu"),
 	/// bz - Cylindrical, other
-	CylindricalOther = (b"bz"[0] as u16) << 8 | b"bz"[1] as u16,
+	CylindricalOther = pack(*b"bz"),
 	/// ca - Albers equal area
-	AlbersEqualArea = (b"ca"[0] as u16) << 8 | b"ca"[1] as u16,
+	AlbersEqualArea = pack(*b"ca"),
 	/// cb - Bonne
-	Bonne = (b"cb"[0] as u16) << 8 | b"cb"[1] as u16,
+	Bonne = pack(*b"cb"),
 	/// cc - Lambert's conformal conic
-	LambertConformalConic = (b"cc"[0] as u16) << 8 | b"cc"[1] as u16,
+	LambertConformalConic = pack(*b"cc"),
 	/// ce - Equidistant conic
-	EquidistantConic = (b"ce"[0] as u16) << 8 | b"ce"[1] as u16,
+	EquidistantConic = pack(*b"ce"),
 	/// cp - Polyconic
-	Polyconic = (b"cp"[0] as u16) << 8 | b"cp"[1] as u16,
+	Polyconic = pack(*b"cp"),
 	/// cu - Conic, specific type unknown
-	ConicUnknown = (b"cu"[0] as u16) << 8 | b"cu"[1] as u16,
+	ConicUnknown = pack(*b"cu"),
 	/// cz - Conic, other
-	ConicOther = (b"cz"[0] as u16) << 8 | b"cz"[1] as u16,
+	ConicOther = pack(*b"cz"),
 	/// da - Armadillo
-	Armadillo = (b"da"[0] as u16) << 8 | b"da"[1] as u16,
+	Armadillo = pack(*b"da"),
 	/// db - Butterfly
-	Butterfly = (b"db"[0] as u16) << 8 | b"db"[1] as u16,
+	Butterfly = pack(*b"db"),
 	/// dc - Eckert
-	Eckert = (b"dc"[0] as u16) << 8 | b"dc"[1] as u16,
+	Eckert = pack(*b"dc"),
 	/// dd - Goode's homolosine
-	GoodeHomolosine = (b"dd"[0] as u16) << 8 | b"dd"[1] as u16,
+	GoodeHomolosine = pack(*b"dd"),
 	/// de - Miller's bipolar oblique conformal conic
-	MillerBipolarObliqueConformal = (b"de"[0] as u16) << 8 | b"de"[1] as u16,
+	MillerBipolarObliqueConformal = pack(*b"de"),
 	/// df - Van Der Grinten
-	VanDerGrinten = (b"df"[0] as u16) << 8 | b"df"[1] as u16,
+	VanDerGrinten = pack(*b"df"),
 	/// dg - Dimaxion
-	Dimaxion = (b"dg"[0] as u16) << 8 | b"dg"[1] as u16,
+	Dimaxion = pack(*b"dg"),
 	/// dh - Cordiform
-	Cordiform = (b"dh"[0] as u16) << 8 | b"dh"[1] as u16,
+	Cordiform = pack(*b"dh"),
 	/// dl - Lambert conformal
-	LambertConformal = (b"dl"[0] as u16) << 8 | b"dl"[1] as u16,
+	LambertConformal = pack(*b"dl"),
 	/// zz - Other
-	Other = (b"zz"[0] as u16) << 8 | b"zz"[1] as u16,
+	Other = pack(*b"zz"),
 	/// || - No attempt to code
 	#[default]
-	NotCoded = (b"||"[0] as u16) << 8 | b"||"[1] as u16,
+	NotCoded = pack(*b"||"),
+}
+
+impl Projection {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			Projection::NotSpecified => "Projection not specified",
+			Projection::Aitoff => "Aitoff",
+			Projection::Gnomic => "Gnomic",
+			Projection::LambertAzimuthal => "Lambert's azimuthal equal area",
+			Projection::Orthographic => "Orthographic",
+			Projection::AzimuthalEquidistant => "Azimuthal equidistant",
+			Projection::Stereographic => "Stereographic",
+			Projection::GeneralVerticalNearSided => "General vertical near-sided",
+			Projection::ModifiedStereographicAlaska => "Modified stereographic for Alaska",
+			Projection::ChamberlinTrimetric => "Chamberlin trimetric",
+			Projection::PolarStereographic => "Polar stereographic",
+			Projection::AzimuthalUnknown => "Azimuthal, specific type unknown",
+			Projection::AzimuthalOther => "Azimuthal, other",
+			Projection::Gall => "Gall",
+			Projection::GoodeHomolographic => "Goode's homolographic",
+			Projection::LambertCylindrical => "Lambert's cylindrical equal area",
+			Projection::Mercator => "Mercator",
+			Projection::Miller => "Miller",
+			Projection::Mollweide => "Mollweide",
+			Projection::Sinusoidal => "Sinusoidal",
+			Projection::TransverseMercator => "Transverse Mercator",
+			Projection::GaussKruger => "Gauss-Kruger",
+			Projection::Equirectangular => "Equirectangular",
+			Projection::Krovak => "Krovak",
+			Projection::CassiniSoldner => "Cassini-Soldner",
+			Projection::ObliqueMercator => "Oblique Mercator",
+			Projection::Robinson => "Robinson",
+			Projection::SpaceObliqueMercator => "Space oblique Mercator",
+			Projection::CylindricalUnknown => "Cylindrical, specific type unknown",
+			Projection::CylindricalOther => "Cylindrical, other",
+			Projection::AlbersEqualArea => "Albers equal area",
+			Projection::Bonne => "Bonne",
+			Projection::LambertConformalConic => "Lambert's conformal conic",
+			Projection::EquidistantConic => "Equidistant conic",
+			Projection::Polyconic => "Polyconic",
+			Projection::ConicUnknown => "Conic, specific type unknown",
+			Projection::ConicOther => "Conic, other",
+			Projection::Armadillo => "Armadillo",
+			Projection::Butterfly => "Butterfly",
+			Projection::Eckert => "Eckert",
+			Projection::GoodeHomolosine => "Goode's homolosine",
+			Projection::MillerBipolarObliqueConformal => "Miller's bipolar oblique conformal conic",
+			Projection::VanDerGrinten => "Van Der Grinten",
+			Projection::Dimaxion => "Dymaxion",
+			Projection::Cordiform => "Cordiform",
+			Projection::LambertConformal => "Lambert conformal",
+			Projection::Other => "Other",
+			Projection::NotCoded => "(not coded)",
+		}
+	}
+}
+
+impl Projection {
+	/// Reads a two-character code, e.g. `*b"bd"` for `Mercator`.
+	/// Blanks are read as `#`, and codes that don't match a variant are `NotCoded`.
+	pub fn from_code(code: [u8; 2]) -> Projection {
+		Projection::from_primitive(pack(code.map(blank_as_hash)))
+	}
+
+	/// The two-character code, e.g. `*b"bd"` for `Mercator`; the inverse of [`Projection::from_code`].
+	pub fn to_code(self) -> [u8; 2] {
+		u16::from(self).to_be_bytes()
+	}
+}
+
+impl FromStr for Projection {
+	type Err = ParseCodeError;
+
+	/// Reads a two-character code, e.g. `"bd"`. Blanks are read as `#`.
+	/// Codes that don't match a variant are `NotCoded`, as when parsing a record.
+	fn from_str(code: &str) -> Result<Self, Self::Err> {
+		two_characters(code).map(Projection::from_code)
+	}
 }
 
 /// The type of the map.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum CartographicType {
 	/// a - Single map
 	SingleMap = b'a',
@@ -656,9 +1494,28 @@ pub enum CartographicType {
 	NotCoded = b'|',
 }
 
+impl CartographicType {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			CartographicType::SingleMap => "Single map",
+			CartographicType::MapSeries => "Map series",
+			CartographicType::MapSerial => "Map serial",
+			CartographicType::Globe => "Globe",
+			CartographicType::Atlas => "Atlas",
+			CartographicType::Supplement => "Separate supplement to another work",
+			CartographicType::Part => "Bound as part of another work",
+			CartographicType::Unknown => "Unknown",
+			CartographicType::Other => "Other",
+			CartographicType::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// Special format characteristics.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpecialFormatCharacteristics {
 	/// # - No specified special format characteristics
 	None = b'#',
@@ -685,163 +1542,292 @@ pub enum SpecialFormatCharacteristics {
 	NotCoded = b'|',
 }
 
+impl SpecialFormatCharacteristics {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			SpecialFormatCharacteristics::None => "No specified special format characteristics",
+			SpecialFormatCharacteristics::Manuscript => "Manuscript",
+			SpecialFormatCharacteristics::PictureCard => "Picture card, post card",
+			SpecialFormatCharacteristics::Calendar => "Calendar",
+			SpecialFormatCharacteristics::Puzzle => "Puzzle",
+			SpecialFormatCharacteristics::Game => "Game",
+			SpecialFormatCharacteristics::WallMap => "Wall map",
+			SpecialFormatCharacteristics::PlayingCards => "Playing cards",
+			SpecialFormatCharacteristics::LooseLeaf => "Loose-leaf",
+			SpecialFormatCharacteristics::Other => "Other",
+			SpecialFormatCharacteristics::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The form of the composition.
 /// Oh boy, another two-letter code, that means more spaghetti.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+///
+/// Half-filled codes like `a|` or ` a` don't match any variant, so the pair decodes to `NotCoded`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u16)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum FormOfComposition {
 	/// an - Anthems
-	Anthems = (b"an"[0] as u16) << 8 | b"an"[1] as u16,
+	Anthems = pack(*b"an"),
 	/// bd - Ballads
-	Ballads = (b"bd"[0] as u16) << 8 | b"bd"[1] as u16,
+	Ballads = pack(*b"bd"),
 	/// bg - Bluegrass music
-	Bluegrass = (b"bg"[0] as u16) << 8 | b"bg"[1] as u16,
+	Bluegrass = pack(*b"bg"),
 	/// bl - Blues
-	Blues = (b"bl"[0] as u16) << 8 | b"bl"[1] as u16,
+	Blues = pack(*b"bl"),
 	/// bt - Ballets
-	Ballet = (b"bt"[0] as u16) << 8 | b"bt"[1] as u16,
+	Ballet = pack(*b"bt"),
 	/// ca - Chaconnes
-	Chaconne = (b"ca"[0] as u16) << 8 | b"ca"[1] as u16,
+	Chaconne = pack(*b"ca"),
 	/// cb - Chants, Other religions
-	Chant = (b"cb"[0] as u16) << 8 | b"cb"[1] as u16,
+	Chant = pack(*b"cb"),
 	/// cc - Chant, Christian
-	ChristianChant = (b"cc"[0] as u16) << 8 | b"cc"[1] as u16,
+	ChristianChant = pack(*b"cc"),
 	/// cg - Concerti grossi
-	Concerti = (b"cg"[0] as u16) << 8 | b"cg"[1] as u16,
+	Concerti = pack(*b"cg"),
 	/// ch - Chorales
-	Chorale = (b"ch"[0] as u16) << 8 | b"ch"[1] as u16,
+	Chorale = pack(*b"ch"),
 	/// cl - Chorale preludes
-	ChoralePrelude = (b"cl"[0] as u16) << 8 | b"cl"[1] as u16,
+	ChoralePrelude = pack(*b"cl"),
 	/// cn - Canons and rounds
-	Canon = (b"cn"[0] as u16) << 8 | b"cn"[1] as u16,
+	Canon = pack(*b"cn"),
 	/// co - Concertos
-	Concerto = (b"co"[0] as u16) << 8 | b"co"[1] as u16,
+	Concerto = pack(*b"co"),
 	/// cp - Chansons, polyphonic
-	Chanson = (b"cp"[0] as u16) << 8 | b"cp"[1] as u16,
+	Chanson = pack(*b"cp"),
 	/// cr - Carols
-	Carols = (b"cr"[0] as u16) << 8 | b"cr"[1] as u16,
+	Carols = pack(*b"cr"),
 	/// cs - Chance compositions
-	Chance = (b"cs"[0] as u16) << 8 | b"cs"[1] as u16,
+	Chance = pack(*b"cs"),
 	/// ct - Cantatas
-	Cantata = (b"ct"[0] as u16) << 8 | b"ct"[1] as u16,
+	Cantata = pack(*b"ct"),
 	/// cy - Country music
-	Country = (b"cy"[0] as u16) << 8 | b"cy"[1] as u16,
+	Country = pack(*b"cy"),
 	/// cz - Canzonas
-	Canzona = (b"cz"[0] as u16) << 8 | b"cz"[1] as u16,
+	Canzona = pack(*b"cz"),
 	/// df - Dance forms
-	Dance = (b"df"[0] as u16) << 8 | b"df"[1] as u16,
+	Dance = pack(*b"df"),
 	/// dv - Divertimentos, serenades, cassations, divertissements, and notturni
-	Divertimento = (b"dv"[0] as u16) << 8 | b"dv"[1] as u16,
+	Divertimento = pack(*b"dv"),
 	/// fg - Fugues
-	Fugue = (b"fg"[0] as u16) << 8 | b"fg"[1] as u16,
+	Fugue = pack(*b"fg"),
 	/// fl - Flamenco
-	Flamenco = (b"fl"[0] as u16) << 8 | b"fl"[1] as u16,
+	Flamenco = pack(*b"fl"),
 	/// fm - Folk music
-	Folk = (b"fm"[0] as u16) << 8 | b"fm"[1] as u16,
+	Folk = pack(*b"fm"),
 	/// ft - Fantasias
-	Fantasia = (b"ft"[0] as u16) << 8 | b"ft"[1] as u16,
+	Fantasia = pack(*b"ft"),
 	/// gm - Gospel music
-	Gospel = (b"gm"[0] as u16) << 8 | b"gm"[1] as u16,
+	Gospel = pack(*b"gm"),
 	/// hy - Hymns
-	Hymn = (b"hy"[0] as u16) << 8 | b"hy"[1] as u16,
+	Hymn = pack(*b"hy"),
 	/// jz - Jazz
-	Jazz = (b"jz"[0] as u16) << 8 | b"jz"[1] as u16,
+	Jazz = pack(*b"jz"),
 	/// mc - Musical revues and comedies
-	Musical = (b"mc"[0] as u16) << 8 | b"mc"[1] as u16,
+	Musical = pack(*b"mc"),
 	/// md - Madrigals
-	Madrigal = (b"md"[0] as u16) << 8 | b"md"[1] as u16,
+	Madrigal = pack(*b"md"),
 	/// mi - Minuets
-	Minuet = (b"mi"[0] as u16) << 8 | b"mi"[1] as u16,
+	Minuet = pack(*b"mi"),
 	/// mo - Motets
-	Motet = (b"mo"[0] as u16) << 8 | b"mo"[1] as u16,
+	Motet = pack(*b"mo"),
 	/// mp - Motion picture music
-	Motion = (b"mp"[0] as u16) << 8 | b"mp"[1] as u16,
+	Motion = pack(*b"mp"),
 	/// mr - Marches
-	March = (b"mr"[0] as u16) << 8 | b"mr"[1] as u16,
+	March = pack(*b"mr"),
 	/// ms - Masses
-	Mass = (b"ms"[0] as u16) << 8 | b"ms"[1] as u16,
+	Mass = pack(*b"ms"),
 	/// mu - Multiple forms
-	Multiple = (b"mu"[0] as u16) << 8 | b"mu"[1] as u16,
+	Multiple = pack(*b"mu"),
 	/// mz - Mazurkas
-	Mazurka = (b"mz"[0] as u16) << 8 | b"mz"[1] as u16,
+	Mazurka = pack(*b"mz"),
 	/// nc - Nocturnes
-	Nocturne = (b"nc"[0] as u16) << 8 | b"nc"[1] as u16,
+	Nocturne = pack(*b"nc"),
 	/// nn - Not applicable
-	NotApplicable = (b"nn"[0] as u16) << 8 | b"nn"[1] as u16,
+	NotApplicable = pack(*b"nn"),
 	/// op - Operas
-	Opera = (b"op"[0] as u16) << 8 | b"op"[1] as u16,
+	Opera = pack(*b"op"),
 	/// or - Oratorios
-	Oratorio = (b"or"[0] as u16) << 8 | b"or"[1] as u16,
+	Oratorio = pack(*b"or"),
 	/// ov - Overtures
-	Overture = (b"ov"[0] as u16) << 8 | b"ov"[1] as u16,
+	Overture = pack(*b"ov"),
 	/// pg - Program music
-	Program = (b"pg"[0] as u16) << 8 | b"pg"[1] as u16,
+	Program = pack(*b"pg"),
 	/// pm - Passion music
-	Passion = (b"pm"[0] as u16) << 8 | b"pm"[1] as u16,
+	Passion = pack(*b"pm"),
 	/// po - Polonaises
-	Polonaise = (b"po"[0] as u16) << 8 | b"po"[1] as u16,
+	Polonaise = pack(*b"po"),
 	/// pp - Popular music
-	Popular = (b"pp"[0] as u16) << 8 | b"pp"[1] as u16,
+	Popular = pack(*b"pp"),
 	/// pr - Preludes
-	Prelude = (b"pr"[0] as u16) << 8 | b"pr"[1] as u16,
+	Prelude = pack(*b"pr"),
 	/// ps - Passacaglias
-	Passacaglia = (b"ps"[0] as u16) << 8 | b"ps"[1] as u16,
+	Passacaglia = pack(*b"ps"),
 	/// pt - Part-songs
-	Part = (b"pt"[0] as u16) << 8 | b"pt"[1] as u16,
+	Part = pack(*b"pt"),
 	/// pv - Pavans
-	Pavan = (b"pv"[0] as u16) << 8 | b"pv"[1] as u16,
+	Pavan = pack(*b"pv"),
 	/// rc - Rock music
-	Rock = (b"rc"[0] as u16) << 8 | b"rc"[1] as u16,
+	Rock = pack(*b"rc"),
 	/// rd - Rondos
-	Rondo = (b"rd"[0] as u16) << 8 | b"rd"[1] as u16,
+	Rondo = pack(*b"rd"),
 	/// rg - Ragtime music
-	Ragtime = (b"rg"[0] as u16) << 8 | b"rg"[1] as u16,
+	Ragtime = pack(*b"rg"),
 	/// ri - Ricercars
-	Ricercar = (b"ri"[0] as u16) << 8 | b"ri"[1] as u16,
+	Ricercar = pack(*b"ri"),
 	/// rp - Rhapsodies
-	Rhapsody = (b"rp"[0] as u16) << 8 | b"rp"[1] as u16,
+	Rhapsody = pack(*b"rp"),
 	/// rq - Requiems
-	Requiem = (b"rq"[0] as u16) << 8 | b"rq"[1] as u16,
+	Requiem = pack(*b"rq"),
 	/// sd - Square dance music
-	Square = (b"sd"[0] as u16) << 8 | b"sd"[1] as u16,
+	Square = pack(*b"sd"),
 	/// sg - Songs
-	Songs = (b"sg"[0] as u16) << 8 | b"sg"[1] as u16,
+	Songs = pack(*b"sg"),
 	/// sn - Sonatas
-	Sonata = (b"sn"[0] as u16) << 8 | b"sn"[1] as u16,
+	Sonata = pack(*b"sn"),
 	/// sp - Symphonic poems
-	Symphonic = (b"sp"[0] as u16) << 8 | b"sp"[1] as u16,
+	Symphonic = pack(*b"sp"),
 	/// st - Studies and exercises
-	Study = (b"st"[0] as u16) << 8 | b"st"[1] as u16,
+	Study = pack(*b"st"),
 	/// su - Suites
-	Suite = (b"su"[0] as u16) << 8 | b"su"[1] as u16,
+	Suite = pack(*b"su"),
 	/// sy - Symphonies
-	Symphony = (b"sy"[0] as u16) << 8 | b"sy"[1] as u16,
+	Symphony = pack(*b"sy"),
 	/// tc - Toccatas
-	Toccata = (b"tc"[0] as u16) << 8 | b"tc"[1] as u16,
+	Toccata = pack(*b"tc"),
 	/// tl - Teatro lirico
-	Teatro = (b"tl"[0] as u16) << 8 | b"tl"[1] as u16,
+	Teatro = pack(*b"tl"),
 	/// ts - Trio-sonatas
-	TrioSonata = (b"ts"[0] as u16) << 8 | b"ts"[1] as u16,
+	TrioSonata = pack(*b"ts"),
 	/// uu - Unknown
-	Unknown = (b"uu"[0] as u16) << 8 | b"uu"[1] as u16,
+	Unknown = pack(*b"uu"),
 	/// vi - Villancicos
-	Villancico = (b"vi"[0] as u16) << 8 | b"vi"[1] as u16,
+	Villancico = pack(*b"vi"),
 	/// vr - Variations
-	Variation = (b"vr"[0] as u16) << 8 | b"vr"[1] as u16,
+	Variation = pack(*b"vr"),
 	/// wz - Waltzes
-	Waltz = (b"wz"[0] as u16) << 8 | b"wz"[1] as u16,
+	Waltz = pack(*b"wz"),
 	/// za - Zarzuelas
-	Zarzuela = (b"za"[0] as u16) << 8 | b"za"[1] as u16,
+	Zarzuela = pack(*b"za"),
 	/// zz - Other
-	Other = (b"zz"[0] as u16) << 8 | b"zz"[1] as u16,
+	Other = pack(*b"zz"),
 	/// || - No attempt to code
 	#[default]
-	NotCoded = (b"||"[0] as u16) << 8 | b"||"[1] as u16,
+	NotCoded = pack(*b"||"),
+}
+
+impl FormOfComposition {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			FormOfComposition::Anthems => "Anthems",
+			FormOfComposition::Ballads => "Ballads",
+			FormOfComposition::Bluegrass => "Bluegrass music",
+			FormOfComposition::Blues => "Blues",
+			FormOfComposition::Ballet => "Ballets",
+			FormOfComposition::Chaconne => "Chaconnes",
+			FormOfComposition::Chant => "Chants, Other religions",
+			FormOfComposition::ChristianChant => "Chant, Christian",
+			FormOfComposition::Concerti => "Concerti grossi",
+			FormOfComposition::Chorale => "Chorales",
+			FormOfComposition::ChoralePrelude => "Chorale preludes",
+			FormOfComposition::Canon => "Canons and rounds",
+			FormOfComposition::Concerto => "Concertos",
+			FormOfComposition::Chanson => "Chansons, polyphonic",
+			FormOfComposition::Carols => "Carols",
+			FormOfComposition::Chance => "Chance compositions",
+			FormOfComposition::Cantata => "Cantatas",
+			FormOfComposition::Country => "Country music",
+			FormOfComposition::Canzona => "Canzonas",
+			FormOfComposition::Dance => "Dance forms",
+			FormOfComposition::Divertimento => {
+				"Divertimentos, serenades, cassations, divertissements, and notturni"
+			}
+			FormOfComposition::Fugue => "Fugues",
+			FormOfComposition::Flamenco => "Flamenco",
+			FormOfComposition::Folk => "Folk music",
+			FormOfComposition::Fantasia => "Fantasias",
+			FormOfComposition::Gospel => "Gospel music",
+			FormOfComposition::Hymn => "Hymns",
+			FormOfComposition::Jazz => "Jazz",
+			FormOfComposition::Musical => "Musical revues and comedies",
+			FormOfComposition::Madrigal => "Madrigals",
+			FormOfComposition::Minuet => "Minuets",
+			FormOfComposition::Motet => "Motets",
+			FormOfComposition::Motion => "Motion picture music",
+			FormOfComposition::March => "Marches",
+			FormOfComposition::Mass => "Masses",
+			FormOfComposition::Multiple => "Multiple forms",
+			FormOfComposition::Mazurka => "Mazurkas",
+			FormOfComposition::Nocturne => "Nocturnes",
+			FormOfComposition::NotApplicable => "Not applicable",
+			FormOfComposition::Opera => "Operas",
+			FormOfComposition::Oratorio => "Oratorios",
+			FormOfComposition::Overture => "Overtures",
+			FormOfComposition::Program => "Program music",
+			FormOfComposition::Passion => "Passion music",
+			FormOfComposition::Polonaise => "Polonaises",
+			FormOfComposition::Popular => "Popular music",
+			FormOfComposition::Prelude => "Preludes",
+			FormOfComposition::Passacaglia => "Passacaglias",
+			FormOfComposition::Part => "Part-songs",
+			FormOfComposition::Pavan => "Pavans",
+			FormOfComposition::Rock => "Rock music",
+			FormOfComposition::Rondo => "Rondos",
+			FormOfComposition::Ragtime => "Ragtime music",
+			FormOfComposition::Ricercar => "Ricercars",
+			FormOfComposition::Rhapsody => "Rhapsodies",
+			FormOfComposition::Requiem => "Requiems",
+			FormOfComposition::Square => "Square dance music",
+			FormOfComposition::Songs => "Songs",
+			FormOfComposition::Sonata => "Sonatas",
+			FormOfComposition::Symphonic => "Symphonic poems",
+			FormOfComposition::Study => "Studies and exercises",
+			FormOfComposition::Suite => "Suites",
+			FormOfComposition::Symphony => "Symphonies",
+			FormOfComposition::Toccata => "Toccatas",
+			FormOfComposition::Teatro => "Teatro lirico",
+			FormOfComposition::TrioSonata => "Trio-sonatas",
+			FormOfComposition::Unknown => "Unknown",
+			FormOfComposition::Villancico => "Villancicos",
+			FormOfComposition::Variation => "Variations",
+			FormOfComposition::Waltz => "Waltzes",
+			FormOfComposition::Zarzuela => "Zarzuelas",
+			FormOfComposition::Other => "Other",
+			FormOfComposition::NotCoded => "(not coded)",
+		}
+	}
+}
+
+impl FormOfComposition {
+	/// Reads a two-character code, e.g. `*b"fg"` for `Fugue`.
+	/// Blanks are read as `#`, and codes that don't match a variant are `NotCoded`.
+	pub fn from_code(code: [u8; 2]) -> FormOfComposition {
+		FormOfComposition::from_primitive(pack(code.map(blank_as_hash)))
+	}
+
+	/// The two-character code, e.g. `*b"fg"` for `Fugue`; the inverse of [`FormOfComposition::from_code`].
+	pub fn to_code(self) -> [u8; 2] {
+		u16::from(self).to_be_bytes()
+	}
+}
+
+impl FromStr for FormOfComposition {
+	type Err = ParseCodeError;
+
+	/// Reads a two-character code, e.g. `"bd"`. Blanks are read as `#`.
+	/// Codes that don't match a variant are `NotCoded`, as when parsing a record.
+	fn from_str(code: &str) -> Result<Self, Self::Err> {
+		two_characters(code).map(FormOfComposition::from_code)
+	}
 }
 
 /// The format of the music.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum FormatOfMusic {
 	/// a - Full score
 	Full = b'a',
@@ -880,9 +1866,35 @@ pub enum FormatOfMusic {
 	NotCoded = b'|',
 }
 
+impl FormatOfMusic {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			FormatOfMusic::Full => "Full score",
+			FormatOfMusic::Miniature => "Miniature or study score",
+			FormatOfMusic::Accompaniment => "Accompaniment reduced for keyboard",
+			FormatOfMusic::Voice => "Voice score with accompaniment omitted",
+			FormatOfMusic::CondensedOrConductor => "Condensed score or piano-conductor score",
+			FormatOfMusic::Close => "Close score",
+			FormatOfMusic::Chorus => "Chorus score",
+			FormatOfMusic::Condensed => "Condensed score",
+			FormatOfMusic::Performer => "Performer-conductor part",
+			FormatOfMusic::Vocal => "Vocal score",
+			FormatOfMusic::Score => "Score",
+			FormatOfMusic::Multiple => "Multiple score formats",
+			FormatOfMusic::Not => "Not applicable",
+			FormatOfMusic::Piano => "Piano score",
+			FormatOfMusic::Unknown => "Unknown",
+			FormatOfMusic::Other => "Other",
+			FormatOfMusic::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The parts in the music.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum MusicParts {
 	/// # - No parts in hand or not specified
 	None = b'#',
@@ -901,9 +1913,25 @@ pub enum MusicParts {
 	NotCoded = b'|',
 }
 
+impl MusicParts {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			MusicParts::None => "No parts in hand or not specified",
+			MusicParts::InstrumentalAndVocal => "Instrumental and vocal parts",
+			MusicParts::Instrumental => "Instrumental parts",
+			MusicParts::Vocal => "Vocal parts",
+			MusicParts::Not => "Not applicable",
+			MusicParts::Unknown => "Unknown",
+			MusicParts::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// Matter accompanying the media.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccompanyingMatter {
 	/// # - No accompanying matter
 	None = b'#',
@@ -938,9 +1966,37 @@ pub enum AccompanyingMatter {
 	NotCoded = b'|',
 }
 
+impl AccompanyingMatter {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			AccompanyingMatter::None => "No accompanying matter",
+			AccompanyingMatter::Discography => "Discography",
+			AccompanyingMatter::Bibliography => "Bibliography",
+			AccompanyingMatter::Thematic => "Thematic index",
+			AccompanyingMatter::Libretto => "Libretto or text",
+			AccompanyingMatter::BiographyComposer => "Biography of composer or author",
+			AccompanyingMatter::BiographyPerformer => {
+				"Biography of performer or history of ensemble"
+			}
+			AccompanyingMatter::TechnicalInstruments => {
+				"Technical and/or historical information on instruments"
+			}
+			AccompanyingMatter::TechnicalMusic => "Technical information on music",
+			AccompanyingMatter::Historical => "Historical information",
+			AccompanyingMatter::Ethnological => "Ethnological information",
+			AccompanyingMatter::Instructional => "Instructional materials",
+			AccompanyingMatter::Music => "Music",
+			AccompanyingMatter::Other => "Other",
+			AccompanyingMatter::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The type of literary text in the recording.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum MusicText {
 	/// # - Item is a music sound recording
 	Music = b'#',
@@ -989,10 +2045,41 @@ pub enum MusicText {
 	NotCoded = b'|',
 }
 
+impl MusicText {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			MusicText::Music => "Item is a music sound recording",
+			MusicText::Autobiography => "Autobiography",
+			MusicText::Biography => "Biography",
+			MusicText::Conference => "Conference proceedings",
+			MusicText::Drama => "Drama",
+			MusicText::Essays => "Essays",
+			MusicText::Fiction => "Fiction",
+			MusicText::Reporting => "Reporting",
+			MusicText::History => "History",
+			MusicText::Instruction => "Instruction",
+			MusicText::Language => "Language instruction",
+			MusicText::Comedy => "Comedy",
+			MusicText::Lectures => "Lectures, speeches",
+			MusicText::Memoirs => "Memoirs",
+			MusicText::Not => "Not applicable",
+			MusicText::Folktales => "Folktales",
+			MusicText::Poetry => "Poetry",
+			MusicText::Rehearsals => "Rehearsals",
+			MusicText::Sounds => "Sounds",
+			MusicText::Interviews => "Interviews",
+			MusicText::Other => "Other",
+			MusicText::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// Whether the music is transposed or arranged.
 
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum TranspositionArrangement {
 	/// # - Not arrangement or transposition or not specified
 	None = b'#',
@@ -1011,9 +2098,25 @@ pub enum TranspositionArrangement {
 	NotCoded = b'|',
 }
 
+impl TranspositionArrangement {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			TranspositionArrangement::None => "Not arrangement or transposition or not specified",
+			TranspositionArrangement::Transposition => "Transposition",
+			TranspositionArrangement::Arrangement => "Arrangement",
+			TranspositionArrangement::Both => "Both transposed and arranged",
+			TranspositionArrangement::NotApplicable => "Not applicable",
+			TranspositionArrangement::Unknown => "Unknown",
+			TranspositionArrangement::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The frequency at which a publication is published.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum Frequency {
 	/// # - No determinable frequency
 	None = b'#',
@@ -1058,9 +2161,38 @@ pub enum Frequency {
 	NotCoded = b'|',
 }
 
+impl Frequency {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			Frequency::None => "No determinable frequency",
+			Frequency::Annual => "Annual",
+			Frequency::Bimonthly => "Bimonthly",
+			Frequency::Semiweekly => "Semiweekly",
+			Frequency::Daily => "Daily",
+			Frequency::Biweekly => "Biweekly",
+			Frequency::Semiannual => "Semiannual",
+			Frequency::Biennial => "Biennial",
+			Frequency::Triennial => "Triennial",
+			Frequency::ThreeWeekly => "Three times a week",
+			Frequency::ThreeMonthly => "Three times a month",
+			Frequency::Continuously => "Continuously updated",
+			Frequency::Monthly => "Monthly",
+			Frequency::Quarterly => "Quarterly",
+			Frequency::Semimonthly => "Semimonthly",
+			Frequency::Three => "Three times a year",
+			Frequency::Unknown => "Unknown",
+			Frequency::Weekly => "Weekly",
+			Frequency::Other => "Other",
+			Frequency::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The regularity with which a publication is published.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum Regularity {
 	/// n - Normalized irregular
 	Normalized = b'n',
@@ -1075,9 +2207,23 @@ pub enum Regularity {
 	NotCoded = b'|',
 }
 
+impl Regularity {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			Regularity::Normalized => "Normalized irregular",
+			Regularity::Regular => "Regular",
+			Regularity::Unknown => "Unknown",
+			Regularity::Completely => "Completely irregular",
+			Regularity::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The type of the periodic publication.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum PublicationType {
 	/// # - None of the following
 	None = b'#',
@@ -1110,9 +2256,32 @@ pub enum PublicationType {
 	NotCoded = b'|',
 }
 
+impl PublicationType {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			PublicationType::None => "None of the following",
+			PublicationType::UpdatingDatabase => "Updating database",
+			PublicationType::Magazine => "Magazine",
+			PublicationType::Blog => "Blog",
+			PublicationType::Journal => "Journal",
+			PublicationType::UpdatingLooseLeaf => "Updating loose-leaf",
+			PublicationType::Monographic => "Monographic series",
+			PublicationType::Newspaper => "Newspaper",
+			PublicationType::Periodical => "Periodical",
+			PublicationType::Repository => "Repository",
+			PublicationType::Newsletter => "Newsletter",
+			PublicationType::Directory => "Directory",
+			PublicationType::UpdatingWeb => "Updating Web site",
+			PublicationType::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// An alphabet or script.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlphabetScript {
 	/// # - No alphabet or script given/No key title
 	None = b'#',
@@ -1149,9 +2318,34 @@ pub enum AlphabetScript {
 	NotCoded = b'|',
 }
 
+impl AlphabetScript {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			AlphabetScript::None => "No alphabet or script given/No key title",
+			AlphabetScript::BasicRoman => "Basic Roman",
+			AlphabetScript::ExtendedRoman => "Extended Roman",
+			AlphabetScript::Cyrillic => "Cyrillic",
+			AlphabetScript::Japanese => "Japanese",
+			AlphabetScript::Chinese => "Chinese",
+			AlphabetScript::Arabic => "Arabic",
+			AlphabetScript::Greek => "Greek",
+			AlphabetScript::Hebrew => "Hebrew",
+			AlphabetScript::Thai => "Thai",
+			AlphabetScript::Devanagari => "Devanagari",
+			AlphabetScript::Korean => "Korean",
+			AlphabetScript::Tamil => "Tamil",
+			AlphabetScript::Unknown => "Unknown",
+			AlphabetScript::Other => "Other",
+			AlphabetScript::NotCoded => "(not coded)",
+		}
+	}
+}
+
 /// The convention according to which the publication is published.
-#[derive(Debug, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde_names", derive(serde::Serialize, serde::Deserialize))]
 pub enum EntryConvention {
 	/// 0 - Successive entry
 	Successive = b'0',
@@ -1163,3 +2357,739 @@ pub enum EntryConvention {
 	#[default]
 	NotCoded = b'|',
 }
+
+impl EntryConvention {
+	/// The label the MARC documentation gives the code, which is also what `Display` prints.
+	pub fn label(self) -> &'static str {
+		match self {
+			EntryConvention::Successive => "Successive entry",
+			EntryConvention::Latest => "Latest entry",
+			EntryConvention::Integrated => "Integrated entry",
+			EntryConvention::NotCoded => "(not coded)",
+		}
+	}
+}
+
+/// Calls a macro with every coded enum and the type of its discriminant.
+macro_rules! coded_enums {
+	($macro:ident) => {
+		$macro! {
+			Illustration(u8),
+			TargetAudience(u8),
+			FormOfItem(u8),
+			NatureOfContents(u8),
+			GovernmentPublication(u8),
+			ConferencePublication(u8),
+			Festschrift(u8),
+			Index(u8),
+			LiteraryForm(u8),
+			Biography(u8),
+			FileType(u8),
+			Relief(u8),
+			Projection(u16),
+			CartographicType(u8),
+			SpecialFormatCharacteristics(u8),
+			FormOfComposition(u16),
+			FormatOfMusic(u8),
+			MusicParts(u8),
+			AccompanyingMatter(u8),
+			MusicText(u8),
+			TranspositionArrangement(u8),
+			Frequency(u8),
+			Regularity(u8),
+			PublicationType(u8),
+			AlphabetScript(u8),
+			EntryConvention(u8),
+		}
+	};
+}
+
+/// Adds a strict `try_from_code` to coded enums, for validating data rather than making a best effort.
+///
+/// The enums already convert from any code through `FromPrimitive`, so `TryFrom` can't be implemented for them.
+macro_rules! strict_codes {
+	($($name:ident($repr:ident),)*) => {$(
+		impl $name {
+			/// Reads a code like `from_primitive`, but fails on codes that don't match any variant
+			/// instead of making them `NotCoded`. Blanks are read as `#`.
+			pub fn try_from_code(code: $repr) -> Result<Self, InvalidCode<$repr>> {
+				let code = code.blanks_as_hashes();
+				match Self::from_primitive(code) {
+					Self::NotCoded if code != Self::NotCoded.into() => Err(InvalidCode(code)),
+					value => Ok(value),
+				}
+			}
+		}
+	)*};
+}
+
+coded_enums!(strict_codes);
+
+/// Adds `all` to coded enums, listing their variants by finding every code that round trips,
+/// so the lists can't fall out of step with the enum definitions.
+macro_rules! all_variants {
+	($($name:ident($repr:ident),)*) => {$(
+		impl $name {
+			/// Every variant, `NotCoded` included, in the order of their codes.
+			pub fn all() -> &'static [Self] {
+				static ALL: OnceLock<Vec<$name>> = OnceLock::new();
+				ALL.get_or_init(|| {
+					(<$repr>::MIN..=<$repr>::MAX)
+						.filter_map(|code| {
+							let value = Self::from_primitive(code);
+							(<$repr>::from(value) == code).then_some(value)
+						})
+						.collect()
+				})
+			}
+		}
+	)*};
+}
+
+coded_enums!(all_variants);
+
+/// The type of a coded enum's code given the type of its discriminant, or the code of a value.
+macro_rules! code {
+	(u8) => {
+		u8
+	};
+	(u16) => {
+		[u8; 2]
+	};
+	(u8, $value:expr) => {
+		u8::from($value)
+	};
+	(u16, $value:expr) => {
+		u16::from($value).to_be_bytes()
+	};
+}
+
+/// Adds `code` and `table` to coded enums, and displays them as their `label`.
+macro_rules! labelled_codes {
+	($($name:ident($repr:ident),)*) => {$(
+		impl $name {
+			/// The code of the variant as it appears in a record, e.g. `b'b'` for [`Illustration::Maps`]
+			/// or `*b"bd"` for [`Projection::Mercator`].
+			pub fn code(self) -> code!($repr) {
+				code!($repr, self)
+			}
+
+			/// Every variant's code and label, in the order of [`all`](Self::all), e.g. for a reference table.
+			pub fn table() -> Vec<(code!($repr), &'static str)> {
+				Self::all().iter().map(|&value| (value.code(), value.label())).collect()
+			}
+		}
+
+		impl fmt::Display for $name {
+			fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+				f.write_str(self.label())
+			}
+		}
+	)*};
+}
+
+coded_enums!(labelled_codes);
+
+/// Serializes coded enums as their code, e.g. `"b"` for [`Illustration::Maps`] or `"bd"` for [`Projection::Mercator`],
+/// and deserializes them strictly from the same.
+///
+/// Enabled by the `serde_support` feature. The `serde_names` feature derives serde's usual variant-name representation instead;
+/// only one of the two can be enabled.
+#[cfg(feature = "serde_support")]
+macro_rules! serde_codes {
+	($($name:ident($repr:ident),)*) => {$(
+		impl serde::Serialize for $name {
+			fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+				serializer.serialize_str(&<$repr>::from(*self).to_chars())
+			}
+		}
+
+		impl<'de> serde::Deserialize<'de> for $name {
+			fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+				use serde::de::{Error, Unexpected};
+				let chars = String::deserialize(deserializer)?;
+				<$repr>::from_chars(&chars)
+					.and_then(|code| Self::try_from_code(code).ok())
+					.ok_or_else(|| {
+						let expected = concat!("a ", stringify!($name), " code");
+						D::Error::invalid_value(Unexpected::Str(&chars), &expected)
+					})
+			}
+		}
+	)*};
+}
+
+#[cfg(feature = "serde_support")]
+coded_enums!(serde_codes);
+
+/// The discriminant types of the coded enums: a single byte, or a two-character code packed into a `u16`.
+trait Code: Sized {
+	/// Reads blanks in the code as `#`, byte by byte.
+	fn blanks_as_hashes(self) -> Self;
+	/// Reads a code written out as text, e.g. `"b"` or `"bd"`.
+	#[cfg_attr(not(feature = "serde_support"), allow(dead_code))]
+	fn from_chars(chars: &str) -> Option<Self>;
+	/// Writes the code out as text.
+	#[cfg_attr(not(feature = "serde_support"), allow(dead_code))]
+	fn to_chars(self) -> String;
+}
+
+impl Code for u8 {
+	fn blanks_as_hashes(self) -> Self {
+		blank_as_hash(self)
+	}
+
+	fn from_chars(chars: &str) -> Option<Self> {
+		match *chars.as_bytes() {
+			[byte] if byte.is_ascii() => Some(byte),
+			_ => None,
+		}
+	}
+
+	fn to_chars(self) -> String {
+		char::from(self).to_string()
+	}
+}
+
+impl Code for u16 {
+	fn blanks_as_hashes(self) -> Self {
+		u16::from_be_bytes(self.to_be_bytes().map(blank_as_hash))
+	}
+
+	fn from_chars(chars: &str) -> Option<Self> {
+		two_characters(chars).ok().map(pack)
+	}
+
+	fn to_chars(self) -> String {
+		self.to_be_bytes().map(char::from).iter().collect()
+	}
+}
+
+/// Packs a two-character code into the `u16` used as the discriminant of the two-character enums.
+const fn pack(code: [u8; 2]) -> u16 {
+	u16::from_be_bytes(code)
+}
+
+/// Checks that a string is a two-character code.
+fn two_characters(code: &str) -> Result<[u8; 2], ParseCodeError> {
+	match *code.as_bytes() {
+		[first, second] if code.is_ascii() => Ok([first, second]),
+		_ => Err(ParseCodeError(code.to_string())),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn half_filled_two_byte_codes_are_not_coded() {
+		for code in [b"a|", b"|a", b" a", b"a "] {
+			assert_eq!(
+				Projection::from_primitive(pack(*code)),
+				Projection::NotCoded
+			);
+			assert_eq!(
+				FormOfComposition::from_primitive(pack(*code)),
+				FormOfComposition::NotCoded
+			);
+		}
+		assert_eq!(
+			Projection::from_primitive(pack(*b"bd")),
+			Projection::Mercator
+		);
+	}
+
+	#[test]
+	fn keeps_raw_half_filled_two_byte_codes() {
+		for code in [*b"a|", *b"|a", *b" a", *b"a "] {
+			let mut map = *b"e    ||           ";
+			map[5..7].copy_from_slice(&code);
+			match AdditionalMaterialCharacteristics::from_field_006(&map).unwrap() {
+				decoded @ AdditionalMaterialCharacteristics::Map {
+					projection: Projection::NotCoded,
+					projection_code,
+					..
+				} => {
+					assert_eq!(projection_code, code);
+					assert_eq!(decoded.to_field_006()[5..7], code);
+				}
+				other => panic!("expected a map, got {:?}", other),
+			}
+
+			let mut music = *b"c||               ";
+			music[1..3].copy_from_slice(&code);
+			match AdditionalMaterialCharacteristics::from_field_006(&music).unwrap() {
+				decoded @ AdditionalMaterialCharacteristics::Music {
+					form_of_composition: FormOfComposition::NotCoded,
+					form_of_composition_code,
+					..
+				} => {
+					assert_eq!(form_of_composition_code, code);
+					assert_eq!(decoded.to_field_006()[1..3], code);
+				}
+				other => panic!("expected music, got {:?}", other),
+			}
+		}
+	}
+
+	#[test]
+	fn book_enums_display_their_labels() {
+		assert_eq!(Illustration::Maps.to_string(), "Maps");
+		assert_eq!(Illustration::None.to_string(), "No illustrations");
+		assert_eq!(TargetAudience::PreAdolescent.to_string(), "Preadolescent");
+		assert_eq!(FormOfItem::LargePrint.to_string(), "Large print");
+		assert_eq!(
+			NatureOfContents::Comics.to_string(),
+			"Comics/graphic novels"
+		);
+		assert_eq!(
+			GovernmentPublication::Federal.to_string(),
+			"Federal/national"
+		);
+		assert_eq!(LiteraryForm::ShortStories.to_string(), "Short stories");
+		assert_eq!(Biography::Collective.to_string(), "Collective biography");
+		assert_eq!(Biography::NotCoded.to_string(), "(not coded)");
+	}
+
+	#[test]
+	fn map_and_computer_file_enums_display_their_labels() {
+		assert_eq!(Relief::Bathymetry.to_string(), "Bathymetry/soundings");
+		assert_eq!(CartographicType::MapSeries.to_string(), "Map series");
+		assert_eq!(
+			SpecialFormatCharacteristics::PictureCard.to_string(),
+			"Picture card, post card"
+		);
+		assert_eq!(
+			FileType::InteractiveMultimedia.to_string(),
+			"Interactive multimedia"
+		);
+		assert_eq!(FileType::NotCoded.to_string(), "(not coded)");
+	}
+
+	#[test]
+	fn projection_displays_full_name() {
+		assert_eq!(
+			Projection::LambertAzimuthal.to_string(),
+			"Lambert's azimuthal equal area"
+		);
+		assert_eq!(Projection::VanDerGrinten.to_string(), "Van Der Grinten");
+		assert_eq!(
+			Projection::NotSpecified.to_string(),
+			"Projection not specified"
+		);
+		assert_eq!(Projection::NotCoded.to_string(), "(not coded)");
+	}
+
+	#[test]
+	fn form_of_composition_displays_genre_name() {
+		assert_eq!(FormOfComposition::Concerti.to_string(), "Concerti grossi");
+		assert_eq!(
+			FormOfComposition::Divertimento.to_string(),
+			"Divertimentos, serenades, cassations, divertissements, and notturni"
+		);
+		assert_eq!(
+			FormOfComposition::NotApplicable.to_string(),
+			"Not applicable"
+		);
+		assert_eq!(FormOfComposition::Unknown.to_string(), "Unknown");
+		assert_eq!(FormOfComposition::Other.to_string(), "Other");
+		assert_eq!(FormOfComposition::NotCoded.to_string(), "(not coded)");
+	}
+
+	#[test]
+	fn parses_two_character_codes() {
+		assert_eq!("bd".parse::<Projection>(), Ok(Projection::Mercator));
+		assert_eq!("  ".parse::<Projection>(), Ok(Projection::NotSpecified));
+		assert_eq!(
+			"dv".parse::<FormOfComposition>(),
+			Ok(FormOfComposition::Divertimento)
+		);
+		assert_eq!(
+			"??".parse::<FormOfComposition>(),
+			Ok(FormOfComposition::NotCoded)
+		);
+		assert_eq!(
+			"b".parse::<Projection>(),
+			Err(ParseCodeError("b".to_string()))
+		);
+		assert!("bdb".parse::<FormOfComposition>().is_err());
+		assert!("\u{e9}".parse::<Projection>().is_err());
+	}
+
+	#[test]
+	fn strict_codes_reject_unknown_codes() {
+		assert_eq!(Illustration::try_from_code(b'?'), Err(InvalidCode(b'?')));
+		assert_eq!(Illustration::try_from_code(b'b'), Ok(Illustration::Maps));
+		assert_eq!(Illustration::try_from_code(b' '), Ok(Illustration::None));
+		assert_eq!(
+			Illustration::try_from_code(b'|'),
+			Ok(Illustration::NotCoded)
+		);
+		assert_eq!(
+			Projection::try_from_code(pack(*b"bd")),
+			Ok(Projection::Mercator)
+		);
+		assert_eq!(
+			Projection::try_from_code(pack(*b"a|")),
+			Err(InvalidCode(pack(*b"a|")))
+		);
+		assert_eq!(
+			FormOfComposition::try_from_code(pack(*b"||")),
+			Ok(FormOfComposition::NotCoded)
+		);
+	}
+
+	#[test]
+	fn two_character_codes_round_trip() {
+		let mut projections = 0;
+		let mut forms = 0;
+		for first in b'!'..=b'~' {
+			for second in b'!'..=b'~' {
+				let code = [first, second];
+				let projection = Projection::from_code(code);
+				if projection != Projection::NotCoded {
+					assert_eq!(projection.to_code(), code);
+					projections += 1;
+				}
+				let form = FormOfComposition::from_code(code);
+				if form != FormOfComposition::NotCoded {
+					assert_eq!(form.to_code(), code);
+					forms += 1;
+				}
+			}
+		}
+		// Every variant but `NotCoded`, which round trips through `||`.
+		assert_eq!(projections, 47);
+		assert_eq!(forms, 72);
+		assert_eq!(Projection::NotCoded.to_code(), *b"||");
+		assert_eq!(
+			FormOfComposition::from_code(*b"||"),
+			FormOfComposition::NotCoded
+		);
+		assert_eq!(Projection::from_code(*b"  "), Projection::NotSpecified);
+	}
+
+	#[cfg(feature = "serde_support")]
+	#[test]
+	fn serde_uses_marc_codes() {
+		use serde::de::{value, IntoDeserializer};
+		use serde::Deserialize;
+		let deserialize = |code: &str| -> Result<Illustration, value::Error> {
+			Illustration::deserialize(code.into_deserializer())
+		};
+		assert_eq!(deserialize("b").unwrap(), Illustration::Maps);
+		assert!(deserialize("?").is_err());
+		assert!(deserialize("bd").is_err());
+		let projection: Result<Projection, value::Error> =
+			Projection::deserialize("bd".into_deserializer());
+		assert_eq!(projection.unwrap(), Projection::Mercator);
+		assert_eq!(u8::from(Illustration::Maps).to_chars(), "b");
+		assert_eq!(u16::from(Projection::Mercator).to_chars(), "bd");
+	}
+
+	#[cfg(feature = "serde_names")]
+	#[test]
+	fn serde_names_uses_variant_names() {
+		use serde::de::{value, IntoDeserializer};
+		use serde::Deserialize;
+		let deserialize = |name: &str| -> Result<Illustration, value::Error> {
+			Illustration::deserialize(name.into_deserializer())
+		};
+		assert_eq!(deserialize("Maps").unwrap(), Illustration::Maps);
+		assert!(deserialize("b").is_err());
+		let projection: Result<Projection, value::Error> =
+			Projection::deserialize("Mercator".into_deserializer());
+		assert_eq!(projection.unwrap(), Projection::Mercator);
+	}
+
+	#[test]
+	fn lists_every_variant() {
+		assert_eq!(Illustration::all().len(), 17);
+		assert_eq!(Illustration::all()[0], Illustration::None);
+		assert_eq!(Illustration::all().last(), Some(&Illustration::NotCoded));
+		assert_eq!(Projection::all().len(), 48);
+		assert_eq!(FormOfComposition::all().len(), 73);
+		for &form in FormOfComposition::all() {
+			assert_eq!(FormOfComposition::from_code(form.to_code()), form);
+		}
+	}
+
+	#[test]
+	fn tables_pair_codes_with_labels() {
+		assert_eq!(Illustration::Maps.code(), b'b');
+		assert_eq!(Illustration::Maps.label(), "Maps");
+		assert_eq!(Projection::Mercator.code(), *b"bd");
+		let table = Index::table();
+		assert_eq!(
+			table,
+			[(b'0', "No index"), (b'1', "Index"), (b'|', "(not coded)")]
+		);
+		for (code, label) in FormOfComposition::table() {
+			assert_eq!(FormOfComposition::from_code(code).to_string(), label);
+		}
+		assert_eq!(Frequency::ThreeWeekly.to_string(), "Three times a week");
+	}
+
+	#[test]
+	fn coded_enums_can_be_map_keys() {
+		let mut counts = std::collections::HashMap::new();
+		for nature in [
+			NatureOfContents::Bibliographies,
+			NatureOfContents::Indexes,
+			NatureOfContents::Bibliographies,
+		] {
+			*counts.entry(nature).or_insert(0) += 1;
+		}
+		assert_eq!(counts[&NatureOfContents::Bibliographies], 2);
+		assert_eq!(counts[&NatureOfContents::Indexes], 1);
+		assert!(!counts.contains_key(&NatureOfContents::Theses));
+	}
+
+	#[test]
+	fn or_default_accessors_fall_back_to_not_coded() {
+		let mixed = AdditionalMaterialCharacteristics::MixedMaterials {
+			form_of_item: FormOfItem::Online,
+		};
+		assert_eq!(mixed.form_of_item_or_default(), FormOfItem::Online);
+		assert_eq!(mixed.target_audience_or_default(), TargetAudience::NotCoded);
+		assert_eq!(
+			mixed.government_publication_or_default(),
+			GovernmentPublication::NotCoded
+		);
+		assert_eq!(
+			mixed.conference_publication_or_default(),
+			ConferencePublication::NotCoded
+		);
+		assert_eq!(mixed.index_or_default(), Index::NotCoded);
+
+		let computer_file = AdditionalMaterialCharacteristics::ComputerFile {
+			target_audience: TargetAudience::Adult,
+			form_of_item: FormOfItem::Online,
+			file_type: FileType::Game,
+			government_publication: GovernmentPublication::Federal,
+		};
+		assert_eq!(
+			computer_file.target_audience_or_default(),
+			TargetAudience::Adult
+		);
+		assert_eq!(
+			computer_file.government_publication_or_default(),
+			GovernmentPublication::Federal
+		);
+	}
+
+	#[test]
+	fn encodes_back_to_field_006() {
+		for field in [
+			b"abf  g      001 1 ",
+			b"m     o  d        ",
+			b"ea   bd a  f  0   ",
+			b"p     o           ",
+			b"jsgnn   a    n  n ",
+			b"smr   o     0   a ",
+		] {
+			let decoded = AdditionalMaterialCharacteristics::from_field_006(field).unwrap();
+			assert_eq!(
+				decoded.to_field_006(),
+				*field,
+				"{}",
+				String::from_utf8_lossy(field)
+			);
+		}
+		let short = AdditionalMaterialCharacteristics::from_field_006(b"ab").unwrap();
+		assert_eq!(&short.to_field_006(), b"ab||||||||||||| ||");
+	}
+
+	#[test]
+	fn converts_from_18_byte_array() {
+		let book = AdditionalMaterialCharacteristics::try_from(b"abf  g      001 1 ").unwrap();
+		assert_eq!(
+			Some(book),
+			AdditionalMaterialCharacteristics::from_field_006(b"abf  g      001 1 ")
+		);
+		assert_eq!(
+			AdditionalMaterialCharacteristics::try_from(b"g                 "),
+			Err(InvalidCode(b'g'))
+		);
+	}
+
+	#[test]
+	fn dispatches_on_position_0() {
+		use AdditionalMaterialCharacteristics as A;
+		let decode = |field: &[u8]| A::from_field_006(field).unwrap();
+		assert!(matches!(
+			decode(b"a"),
+			A::Book {
+				manuscript: false,
+				..
+			}
+		));
+		assert!(matches!(
+			decode(b"t"),
+			A::Book {
+				manuscript: true,
+				..
+			}
+		));
+		assert!(matches!(decode(b"m"), A::ComputerFile { .. }));
+		assert!(matches!(
+			decode(b"e"),
+			A::Map {
+				manuscript: false,
+				..
+			}
+		));
+		assert!(matches!(
+			decode(b"f"),
+			A::Map {
+				manuscript: true,
+				..
+			}
+		));
+		assert!(matches!(decode(b"p"), A::MixedMaterials { .. }));
+		assert!(matches!(
+			decode(b"c"),
+			A::Music {
+				recorded: true,
+				manuscript: false,
+				..
+			}
+		));
+		assert!(matches!(
+			decode(b"d"),
+			A::Music {
+				recorded: true,
+				manuscript: true,
+				..
+			}
+		));
+		assert!(matches!(
+			decode(b"i"),
+			A::Music {
+				recorded: false,
+				musical: false,
+				..
+			}
+		));
+		assert!(matches!(
+			decode(b"j"),
+			A::Music {
+				recorded: false,
+				musical: true,
+				..
+			}
+		));
+		assert!(matches!(decode(b"s"), A::ContinuingResources { .. }));
+		assert!(A::from_field_006(b"").is_none());
+		assert!(A::from_field_006(b"g").is_none());
+	}
+
+	#[test]
+	fn decodes_music() {
+		match AdditionalMaterialCharacteristics::from_field_006(b"jsgnn   a    n  n ") {
+			Some(AdditionalMaterialCharacteristics::Music {
+				recorded,
+				manuscript,
+				musical,
+				form_of_composition,
+				form_of_composition_code,
+				format_of_music,
+				parts,
+				target_audience,
+				form_of_item,
+				accompanying_matter,
+				literary_text,
+				transposition_and_arrangement,
+			}) => {
+				assert!(!recorded);
+				assert!(!manuscript);
+				assert!(musical);
+				assert_eq!(form_of_composition, FormOfComposition::Songs);
+				assert_eq!(form_of_composition_code, *b"sg");
+				assert_eq!(format_of_music, FormatOfMusic::Not);
+				assert_eq!(parts, MusicParts::Not);
+				assert_eq!(target_audience, TargetAudience::Unknown);
+				assert_eq!(form_of_item, FormOfItem::None);
+				assert_eq!(accompanying_matter[0], AccompanyingMatter::None);
+				assert_eq!(accompanying_matter[1], AccompanyingMatter::Discography);
+				assert_eq!(literary_text, [MusicText::Not, MusicText::Music]);
+				assert_eq!(
+					transposition_and_arrangement,
+					TranspositionArrangement::NotApplicable
+				);
+			}
+			other => panic!("expected music, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn decodes_continuing_resources() {
+		match AdditionalMaterialCharacteristics::from_field_006(b"smr p o     0   a0") {
+			Some(AdditionalMaterialCharacteristics::ContinuingResources {
+				frequency,
+				regularity,
+				form_of_original,
+				form_of_current,
+				conference_publication,
+				original_alphabet_or_script,
+				..
+			}) => {
+				assert_eq!(frequency, Frequency::Monthly);
+				assert_eq!(regularity, Regularity::Regular);
+				assert_eq!(form_of_original, FormOfItem::None);
+				assert_eq!(form_of_current, FormOfItem::Online);
+				assert_eq!(conference_publication, ConferencePublication::NonConference);
+				assert_eq!(original_alphabet_or_script, AlphabetScript::BasicRoman);
+			}
+			other => panic!("expected a continuing resource, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn short_field_falls_back_to_not_coded() {
+		match AdditionalMaterialCharacteristics::from_field_006(b"ab") {
+			Some(AdditionalMaterialCharacteristics::Book {
+				illustrations,
+				target_audience,
+				biography,
+				..
+			}) => {
+				assert_eq!(
+					illustrations,
+					[
+						Illustration::Maps,
+						Illustration::NotCoded,
+						Illustration::NotCoded,
+						Illustration::NotCoded
+					]
+				);
+				assert_eq!(target_audience, TargetAudience::NotCoded);
+				assert_eq!(biography, Biography::NotCoded);
+			}
+			other => panic!("expected a book, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn debug_positions_labels_each_position() {
+		let map = AdditionalMaterialCharacteristics::Map {
+			manuscript: true,
+			relief: [Relief::Contours, Relief::None, Relief::None, Relief::None],
+			projection: Projection::Mercator,
+			projection_code: *b"bd",
+			cartographic_type: CartographicType::SingleMap,
+			government_publication: GovernmentPublication::Federal,
+			form_of_item: FormOfItem::None,
+			index: Index::None,
+			special_format_characteristics: [
+				SpecialFormatCharacteristics::WallMap,
+				SpecialFormatCharacteristics::None,
+			],
+		};
+		assert_eq!(
+			map.debug_positions(),
+			"[0]=f manuscript=true [1-4]=relief: Contours,None,None,None [5-6]=projection: Mercator [8]=cartographic_type: SingleMap [11]=government_publication: Federal [12]=form_of_item: None [14]=index: None [16-17]=special_format_characteristics: WallMap,None"
+		);
+	}
+}
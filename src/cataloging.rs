@@ -0,0 +1,53 @@
+//! Who created and changed a record, and by what rules.
+
+use crate::field::Field;
+
+/// The agencies that created, transcribed and modified a record, from field 040.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd040.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CatalogingSource {
+	/// The code of the agency that created the original record, e.g. `DLC`, from subfield `a`.
+	pub original_agency: Option<String>,
+	/// The code of the agency that transcribed the record into machine-readable form, from subfield `c`.
+	pub transcribing_agency: Option<String>,
+	/// The codes of the agencies that modified the record, in order, from subfield `d`.
+	pub modifying_agencies: Vec<String>,
+	/// The description conventions the record follows, e.g. `rda`, from subfield `e`.
+	/// Records without one usually follow AACR2.
+	pub description_conventions: Option<String>,
+}
+
+impl CatalogingSource {
+	/// Reads a field 040.
+	pub(crate) fn from_field_040(field: &Field) -> CatalogingSource {
+		let code = |value: &str| value.trim().to_string();
+		CatalogingSource {
+			original_agency: field.subfield(b'a').map(code),
+			transcribing_agency: field.subfield(b'c').map(code),
+			modifying_agencies: field.subfields(b'd').map(code).collect(),
+			description_conventions: field.subfield(b'e').map(code),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn reads_agencies_and_conventions() {
+		assert_eq!(
+			CatalogingSource::from_field_040(&data_field("  $aDLC$beng$erda$cDLC$dOCLCQ$dUKMGB")),
+			CatalogingSource {
+				original_agency: Some("DLC".to_string()),
+				transcribing_agency: Some("DLC".to_string()),
+				modifying_agencies: vec!["OCLCQ".to_string(), "UKMGB".to_string()],
+				description_conventions: Some("rda".to_string()),
+			}
+		);
+	}
+}
@@ -0,0 +1,123 @@
+//! Where a resource goes on the shelf.
+
+use crate::blank_as_hash;
+use crate::field::Field;
+use num_enum::FromPrimitive;
+
+/// A Library of Congress call number, from field 050.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd050.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CallNumber {
+	/// The classification number, e.g. `PS3511.I9`, from subfield `a`.
+	pub classification: String,
+	/// The item number, usually a Cutter number and date, e.g. `G7 1925`, from subfield `b`.
+	pub item: Option<String>,
+	/// Who assigned the call number, from the second indicator.
+	pub source: CallNumberSource,
+}
+
+impl CallNumber {
+	/// Reads a field 050.
+	pub(crate) fn from_field_050(field: &Field) -> CallNumber {
+		CallNumber {
+			classification: field.subfield(b'a').unwrap_or_default().trim().to_string(),
+			item: field.subfield(b'b').map(|item| item.trim().to_string()),
+			source: CallNumberSource::from_primitive(blank_as_hash(field.indicators[1])),
+		}
+	}
+}
+
+/// Who assigned a call number.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum CallNumberSource {
+	/// 0 - Assigned by LC
+	LibraryOfCongress = b'0',
+	/// 4 - Assigned by agency other than LC
+	OtherAgency = b'4',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// A Dewey Decimal Classification number, from field 082.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd082.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DeweyNumber {
+	/// The classification number as written, e.g. `813/.52`, from subfield `a`.
+	/// Slashes mark where the number may be shortened.
+	pub number: String,
+	/// The edition of the schedules the number comes from, e.g. `22`, from subfield `2`.
+	pub edition: Option<String>,
+	/// Whether the number comes from the full or abridged schedules, from the first indicator.
+	pub kind: DeweyEdition,
+}
+
+impl DeweyNumber {
+	/// Reads a field 082.
+	pub(crate) fn from_field_082(field: &Field) -> DeweyNumber {
+		DeweyNumber {
+			number: field.subfield(b'a').unwrap_or_default().trim().to_string(),
+			edition: field
+				.subfield(b'2')
+				.map(|edition| edition.trim().to_string()),
+			kind: DeweyEdition::from_primitive(blank_as_hash(field.indicators[0])),
+		}
+	}
+}
+
+/// Which Dewey schedules a number comes from.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum DeweyEdition {
+	/// 0 - Full edition
+	Full = b'0',
+	/// 1 - Abridged edition
+	Abridged = b'1',
+	/// 7 - Other edition specified in subfield $2
+	Other = b'7',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn splits_class_and_item() {
+		assert_eq!(
+			CallNumber::from_field_050(&data_field("00$aPS3511.I9$bG7 1925")),
+			CallNumber {
+				classification: "PS3511.I9".to_string(),
+				item: Some("G7 1925".to_string()),
+				source: CallNumberSource::LibraryOfCongress,
+			}
+		);
+		let local = CallNumber::from_field_050(&data_field(" 4$aQA76.73.R87"));
+		assert_eq!(local.item, None);
+		assert_eq!(local.source, CallNumberSource::OtherAgency);
+	}
+
+	#[test]
+	fn reads_dewey_number_and_edition() {
+		assert_eq!(
+			DeweyNumber::from_field_082(&data_field("00$a813/.52$222")),
+			DeweyNumber {
+				number: "813/.52".to_string(),
+				edition: Some("22".to_string()),
+				kind: DeweyEdition::Full,
+			}
+		);
+		let abridged = DeweyNumber::from_field_082(&data_field("14$a813$215"));
+		assert_eq!(abridged.kind, DeweyEdition::Abridged);
+	}
+}
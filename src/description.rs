@@ -0,0 +1,104 @@
+//! What a resource physically is: its extent, size and format.
+
+use crate::field::{trim_punctuation, Field};
+
+/// The physical description of a resource, from field 300.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd300.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PhysicalDescription {
+	/// The number and kind of units, e.g. `324 pages`, from subfield `a`.
+	pub extent: Vec<String>,
+	/// Other physical details, e.g. `illustrations`, from subfield `b`.
+	pub other_details: Option<String>,
+	/// The size of the resource, e.g. `24 cm`, from subfield `c`.
+	pub dimensions: Option<String>,
+	/// Material issued with the resource, e.g. `1 CD-ROM`, from subfield `e`.
+	pub accompanying: Vec<String>,
+}
+
+impl PhysicalDescription {
+	/// Reads a field 300.
+	pub(crate) fn from_field_300(field: &Field) -> PhysicalDescription {
+		let all = |code| field.subfields(code).map(trim_punctuation).collect();
+		PhysicalDescription {
+			extent: all(b'a'),
+			other_details: field.subfield(b'b').map(trim_punctuation),
+			dimensions: field.subfield(b'c').map(trim_punctuation),
+			accompanying: all(b'e'),
+		}
+	}
+}
+
+/// A term from one of the RDA vocabularies used in fields 336, 337 and 338, which say what kind of content a resource has
+/// and what it's carried on.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd336.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RdaTerm {
+	/// The term, e.g. `text`, `unmediated` or `volume`, from subfield `a`.
+	pub term: String,
+	/// The code for the term, e.g. `txt`, `n` or `nc`, from subfield `b`.
+	pub code: Option<String>,
+	/// The vocabulary the term comes from, e.g. `rdacontent`, from subfield `2`.
+	pub source: Option<String>,
+}
+
+impl RdaTerm {
+	/// Reads a field 336, 337 or 338.
+	pub(crate) fn from_field(field: &Field) -> RdaTerm {
+		RdaTerm {
+			term: field.subfield(b'a').unwrap_or_default().to_string(),
+			code: field.subfield(b'b').map(str::to_string),
+			source: field.subfield(b'2').map(str::to_string),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn reads_physical_description() {
+		let description = PhysicalDescription::from_field_300(&data_field(
+			"  $a324 pages :$billustrations ;$c24 cm",
+		));
+		assert_eq!(
+			description,
+			PhysicalDescription {
+				extent: vec!["324 pages".to_string()],
+				other_details: Some("illustrations".to_string()),
+				dimensions: Some("24 cm".to_string()),
+				accompanying: vec![],
+			}
+		);
+	}
+
+	#[test]
+	fn keeps_repeated_extents_and_accompanying_material() {
+		let description = PhysicalDescription::from_field_300(&data_field(
+			"  $a1 score (32 pages) ;$a2 parts ;$c31 cm +$e1 CD-ROM",
+		));
+		assert_eq!(description.extent, ["1 score (32 pages)", "2 parts"]);
+		assert_eq!(description.dimensions.as_deref(), Some("31 cm"));
+		assert_eq!(description.accompanying, ["1 CD-ROM"]);
+	}
+
+	#[test]
+	fn reads_rda_term() {
+		assert_eq!(
+			RdaTerm::from_field(&data_field("  $acomputer$bc$2rdamedia")),
+			RdaTerm {
+				term: "computer".to_string(),
+				code: Some("c".to_string()),
+				source: Some("rdamedia".to_string()),
+			}
+		);
+	}
+}
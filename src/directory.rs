@@ -0,0 +1,160 @@
+//! The directory between the leader and the data, which locates each field in the record.
+
+use crate::error::ParseError;
+use crate::{parse_number, FIELD_TERMINATOR};
+use std::ops::Range;
+
+/// One 12-byte directory entry: a tag, a length and an offset.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DirectoryEntry {
+	/// The field's tag, e.g. `*b"245"`.
+	pub tag: [u8; 3],
+	/// The length of the field, including its field terminator.
+	pub length: usize,
+	/// The offset of the field from the base address of data.
+	pub offset: usize,
+}
+
+impl DirectoryEntry {
+	/// The tag as a string, e.g. `"245"`.
+	pub fn tag_str(&self) -> String {
+		String::from_utf8_lossy(&self.tag).into_owned()
+	}
+
+	/// The byte range the field occupies within the record.
+	pub fn range(&self, base_address: usize) -> Range<usize> {
+		base_address + self.offset..base_address + self.offset + self.length
+	}
+}
+
+/// Reads the directory of a record.
+///
+/// `bytes` is the whole record, starting with the leader;
+/// entries are read from position 24 until the field terminator that closes the directory.
+/// Every entry is checked against the length of `bytes`, so slicing a field with [`DirectoryEntry::range`] won't panic,
+/// and against `record_length` from the leader unless that's 0 for unknown.
+pub fn parse_directory(
+	bytes: &[u8],
+	base_address: usize,
+	record_length: usize,
+) -> Result<Vec<DirectoryEntry>, ParseError> {
+	if base_address < 24 {
+		return Err(ParseError::BaseAddressTooSmall(base_address));
+	}
+	if base_address > bytes.len() {
+		return Err(ParseError::BaseAddressOutOfBounds {
+			base_address,
+			length: bytes.len(),
+		});
+	}
+	let length = match record_length {
+		0 => bytes.len(),
+		record_length => record_length.min(bytes.len()),
+	};
+	let mut directory = &bytes[24..base_address];
+	let mut entries = Vec::new();
+	while !directory.is_empty() && directory[0] != FIELD_TERMINATOR {
+		if directory.len() < 12 {
+			return Err(ParseError::TruncatedDirectory);
+		}
+		let entry = DirectoryEntry {
+			tag: directory[0..3].try_into().unwrap(),
+			length: parse_number(&directory[3..7], "field length")?,
+			offset: parse_number(&directory[7..12], "field offset")?,
+		};
+		let end = entry.range(base_address).end;
+		if end > length {
+			return Err(ParseError::FieldOutOfBounds {
+				tag: entry.tag_str(),
+				end,
+				length,
+			});
+		}
+		entries.push(entry);
+		directory = &directory[12..];
+	}
+	Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::record;
+
+	#[test]
+	fn parses_entries() {
+		let bytes = record(&[("001", "12345"), ("245", "00$aTitle")]);
+		let base_address = crate::parse_number(&bytes[12..17], "base address").unwrap();
+		let entries = parse_directory(&bytes, base_address, bytes.len()).unwrap();
+		assert_eq!(
+			entries,
+			vec![
+				DirectoryEntry {
+					tag: *b"001",
+					length: 6,
+					offset: 0
+				},
+				DirectoryEntry {
+					tag: *b"245",
+					length: 10,
+					offset: 6
+				},
+			]
+		);
+		assert_eq!(&bytes[entries[0].range(base_address)], b"12345\x1E");
+	}
+
+	#[test]
+	fn rejects_entry_past_end_of_record() {
+		let mut bytes = record(&[("001", "12345"), ("245", "00$aTitle")]);
+		// Stretch the 245 entry's length from 0010 to 0090.
+		bytes[24 + 12 + 3..24 + 12 + 7].copy_from_slice(b"0090");
+		let base_address = crate::parse_number(&bytes[12..17], "base address").unwrap();
+		match parse_directory(&bytes, base_address, bytes.len()) {
+			Err(ParseError::FieldOutOfBounds { tag, end, length }) => {
+				assert_eq!(tag, "245");
+				assert_eq!(end, base_address + 6 + 90);
+				assert_eq!(length, bytes.len());
+			}
+			other => panic!("expected FieldOutOfBounds, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn rejects_entry_past_record_length() {
+		let bytes = record(&[("001", "12345"), ("245", "00$aTitle")]);
+		let base_address = crate::parse_number(&bytes[12..17], "base address").unwrap();
+		// The 245 ends at base_address + 16; a leader claiming less cuts it off even though the bytes are there.
+		match parse_directory(&bytes, base_address, base_address + 10) {
+			Err(ParseError::FieldOutOfBounds { tag, end, length }) => {
+				assert_eq!(tag, "245");
+				assert_eq!(end, base_address + 16);
+				assert_eq!(length, base_address + 10);
+			}
+			other => panic!("expected FieldOutOfBounds, got {:?}", other),
+		}
+		// 0 means the leader's length couldn't be read, so only the bytes count.
+		assert_eq!(parse_directory(&bytes, base_address, 0).unwrap().len(), 2);
+	}
+
+	#[test]
+	fn rejects_base_address_inside_leader() {
+		let bytes = record(&[("001", "12345")]);
+		assert!(matches!(
+			parse_directory(&bytes, 12, bytes.len()),
+			Err(ParseError::BaseAddressTooSmall(12))
+		));
+	}
+
+	#[test]
+	fn rejects_truncated_entry() {
+		let mut bytes = record(&[("001", "12345")]);
+		// Drop the directory's terminator and pretend a partial entry follows.
+		bytes[24 + 12] = b'2';
+		let base_address = crate::parse_number(&bytes[12..17], "base address").unwrap();
+		assert!(matches!(
+			parse_directory(&bytes, base_address, bytes.len()),
+			Err(ParseError::TruncatedDirectory)
+		));
+	}
+}
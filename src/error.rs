@@ -0,0 +1,234 @@
+//! Errors that can occur while reading a MARC21 record.
+
+use std::fmt;
+
+/// Something went wrong while parsing a record.
+#[derive(Debug)]
+pub enum ParseError {
+	/// The record is too short to contain a 24-byte leader.
+	LeaderTooShort(usize),
+	/// A numeric leader or directory value contains something other than ASCII digits.
+	InvalidNumber {
+		/// What the number was supposed to be.
+		what: &'static str,
+		/// The bytes that were found instead.
+		found: Vec<u8>,
+	},
+	/// A coded leader position holds a value MARC21 doesn't define.
+	InvalidLeader {
+		/// The position within the leader.
+		position: usize,
+		/// The byte that was found there.
+		found: u8,
+	},
+	/// Leader positions 20-23 are not `4500`.
+	InvalidEntryMap(Vec<u8>),
+	/// The leader's base address of data points past the end of the record.
+	BaseAddressOutOfBounds {
+		/// The base address of data given by the leader.
+		base_address: usize,
+		/// The length of the record.
+		length: usize,
+	},
+	/// The leader's base address of data points inside the leader, so there's no room for a directory.
+	BaseAddressTooSmall(usize),
+	/// The directory ends partway through an entry.
+	TruncatedDirectory,
+	/// A directory entry points past the end of the record.
+	FieldOutOfBounds {
+		/// The tag of the offending field.
+		tag: String,
+		/// The offset of the field's end from the start of the record.
+		end: usize,
+		/// The length of the record: the leader's record length, or the bytes available if those are fewer.
+		length: usize,
+	},
+	/// A field's contents are not valid UTF-8.
+	InvalidUtf8 {
+		/// The tag of the offending field.
+		tag: String,
+	},
+	/// The input ended before the number of bytes given by the record length.
+	TruncatedRecord {
+		/// The record length given by the leader.
+		expected: usize,
+		/// The number of bytes that could be read.
+		found: usize,
+	},
+	/// Reading from the underlying stream failed.
+	Io(std::io::Error),
+}
+
+impl fmt::Display for ParseError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseError::LeaderTooShort(length) => {
+				write!(
+					f,
+					"record is {} bytes long, too short for a 24-byte leader",
+					length
+				)
+			}
+			ParseError::InvalidNumber { what, found } => {
+				write!(f, "invalid {}: {:?}", what, String::from_utf8_lossy(found))
+			}
+			ParseError::InvalidLeader { position, found } => write!(
+				f,
+				"invalid value {:?} at leader position {}",
+				*found as char, position
+			),
+			ParseError::InvalidEntryMap(found) => write!(
+				f,
+				"leader entry map is {:?}, expected \"4500\"",
+				String::from_utf8_lossy(found)
+			),
+			ParseError::BaseAddressOutOfBounds {
+				base_address,
+				length,
+			} => write!(
+				f,
+				"base address of data {} is past the end of the {}-byte record",
+				base_address, length
+			),
+			ParseError::BaseAddressTooSmall(base_address) => write!(
+				f,
+				"base address of data {} is inside the 24-byte leader",
+				base_address
+			),
+			ParseError::TruncatedDirectory => write!(f, "directory ends partway through an entry"),
+			ParseError::FieldOutOfBounds { tag, end, length } => write!(
+				f,
+				"field {} ends at byte {}, past the end of the {}-byte record",
+				tag, end, length
+			),
+			ParseError::InvalidUtf8 { tag } => write!(f, "field {} is not valid UTF-8", tag),
+			ParseError::TruncatedRecord { expected, found } => write!(
+				f,
+				"record should be {} bytes long, but the input ended after {}",
+				expected, found
+			),
+			ParseError::Io(error) => write!(f, "couldn't read record: {}", error),
+		}
+	}
+}
+
+impl std::error::Error for ParseError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			ParseError::Io(error) => Some(error),
+			_ => None,
+		}
+	}
+}
+
+impl From<std::io::Error> for ParseError {
+	fn from(error: std::io::Error) -> Self {
+		ParseError::Io(error)
+	}
+}
+
+/// A string parsed as a two-character code, e.g. `"bd".parse::<Projection>()`, isn't two ASCII characters long.
+///
+/// [`Projection`]: crate::additional_material_characteristics::Projection
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseCodeError(pub String);
+
+impl fmt::Display for ParseCodeError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:?} is not a two-character code", self.0)
+	}
+}
+
+impl std::error::Error for ParseCodeError {}
+
+/// A code that doesn't match any variant of a coded enum, from a strict `try_from_code`,
+/// e.g. [`Illustration::try_from_code`].
+///
+/// Holds a single byte, or a two-character code packed into a `u16`.
+///
+/// [`Illustration::try_from_code`]: crate::additional_material_characteristics::Illustration::try_from_code
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct InvalidCode<T = u8>(pub T);
+
+impl fmt::Display for InvalidCode<u8> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:?} is not a valid code", self.0 as char)
+	}
+}
+
+impl fmt::Display for InvalidCode<u16> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let [first, second] = self.0.to_be_bytes();
+		write!(
+			f,
+			"{:?} is not a valid code",
+			String::from_utf8_lossy(&[first, second])
+		)
+	}
+}
+
+impl std::error::Error for InvalidCode<u8> {}
+
+impl std::error::Error for InvalidCode<u16> {}
+
+/// Something odd about a record that didn't stop it from being parsed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseWarning {
+	/// The record doesn't end with a record terminator.
+	MissingRecordTerminator,
+	/// A field doesn't end with a field terminator, so its last byte was kept as data.
+	MissingFieldTerminator {
+		/// The tag of the offending field.
+		tag: String,
+	},
+	/// Leader position 9 is neither blank (MARC-8) nor `a` (UCS/Unicode), so the record was read as UTF-8.
+	UnknownCharacterCodingScheme {
+		/// The byte at leader position 9.
+		found: u8,
+	},
+	/// The record length in leader positions 0-4 doesn't match the bytes given,
+	/// e.g. because of trailing bytes after the record, or a length that wasn't updated after editing.
+	/// Fields are located through the directory, so they're unaffected.
+	RecordLengthMismatch {
+		/// The length the leader gives.
+		expected: usize,
+		/// The number of bytes given.
+		found: usize,
+	},
+	/// A language code subfield of field 041 doesn't split into three-letter codes, e.g. `fr`.
+	/// Any whole codes before the leftover characters are still read.
+	MalformedLanguageCode {
+		/// The subfield code, e.g. `b'a'`.
+		code: u8,
+		/// The subfield's value.
+		found: String,
+	},
+}
+
+impl fmt::Display for ParseWarning {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ParseWarning::MissingRecordTerminator => {
+				write!(f, "record doesn't end with a record terminator")
+			}
+			ParseWarning::MissingFieldTerminator { tag } => {
+				write!(f, "field {} doesn't end with a field terminator", tag)
+			}
+			ParseWarning::UnknownCharacterCodingScheme { found } => write!(
+				f,
+				"unknown character coding scheme {:?}, reading as UTF-8",
+				*found as char
+			),
+			ParseWarning::RecordLengthMismatch { expected, found } => write!(
+				f,
+				"leader gives a record length of {} bytes, but the record is {} bytes",
+				expected, found
+			),
+			ParseWarning::MalformedLanguageCode { code, found } => write!(
+				f,
+				"041 ${} {:?} isn't made of three-letter language codes",
+				*code as char, found
+			),
+		}
+	}
+}
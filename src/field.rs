@@ -0,0 +1,208 @@
+//! Fields of a record, with their subfields decoded into text.
+//!
+//! [`Marc`](crate::Marc) parses the fields it knows into dedicated types,
+//! but every field is also available in this generic form through [`Marc::raw_fields`](crate::Marc::raw_fields).
+
+use crate::error::ParseError;
+use crate::leader::{CharacterCodingScheme, Leader};
+use crate::{marc8, ParseOptions, SUBFIELD_DELIMITER};
+
+/// A field of a record, split into its indicators and subfields.
+///
+/// Control fields (tags 00X) have no indicators or subfields;
+/// their value is in `control_value` instead, and their indicators are left blank.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Field {
+	/// The tag of the field, e.g. `245`.
+	pub tag: String,
+	/// The two indicators, with blanks left as spaces.
+	pub indicators: [u8; 2],
+	/// The subfields, in order. Empty for control fields.
+	pub subfields: Vec<Subfield>,
+	/// The value of a control field, e.g. the control number in field 001. `None` for data fields.
+	pub control_value: Option<String>,
+}
+
+/// A subfield of a [`Field`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Subfield {
+	/// The subfield code, e.g. `b'a'`.
+	pub code: u8,
+	/// The value of the subfield.
+	pub value: String,
+}
+
+impl Field {
+	/// Decodes a data field, whose contents are two indicators followed by subfields,
+	/// each introduced by a [`SUBFIELD_DELIMITER`] and a one-byte code.
+	///
+	/// Missing indicators read as blanks, and anything else before the first delimiter is ignored.
+	/// Subfield values are trimmed if `options.trim_subfield_whitespace` is set.
+	pub(crate) fn parse(
+		leader: &Leader,
+		options: ParseOptions,
+		tag: &str,
+		data: &[u8],
+	) -> Result<Field, ParseError> {
+		let before_subfields = data.split(|&byte| byte == SUBFIELD_DELIMITER).next();
+		let indicator = |position: usize| {
+			before_subfields
+				.and_then(|bytes| bytes.get(position))
+				.copied()
+				.unwrap_or(b' ')
+		};
+		let indicators = [indicator(0), indicator(1)];
+		let subfields = data
+			.split(|&byte| byte == SUBFIELD_DELIMITER)
+			.skip(1)
+			.filter_map(|subfield| subfield.split_first())
+			.map(|(&code, value)| {
+				let value = decode(leader, options, tag, value)?;
+				Ok(Subfield {
+					code,
+					value: if options.trim_subfield_whitespace {
+						value.trim().to_string()
+					} else {
+						value
+					},
+				})
+			})
+			.collect::<Result<_, ParseError>>()?;
+		Ok(Field {
+			tag: tag.to_string(),
+			indicators,
+			subfields,
+			control_value: None,
+		})
+	}
+
+	/// Wraps the value of a control field.
+	pub(crate) fn control(tag: &str, value: String) -> Field {
+		Field {
+			tag: tag.to_string(),
+			indicators: *b"  ",
+			subfields: Vec::new(),
+			control_value: Some(value),
+		}
+	}
+
+	/// Whether this is a control field (tag 00X), whose value is in `control_value` rather than subfields.
+	pub fn is_control(&self) -> bool {
+		self.tag.starts_with("00")
+	}
+
+	/// The two indicators, e.g. `*b" 0"` for a Library of Congress subject heading.
+	/// Blank indicators are spaces.
+	pub fn indicators(&self) -> [u8; 2] {
+		self.indicators
+	}
+
+	/// The value of the first subfield with the given code.
+	pub fn subfield(&self, code: u8) -> Option<&str> {
+		self.subfields(code).next()
+	}
+
+	/// The values of every subfield with the given code, in order.
+	pub fn subfields(&self, code: u8) -> impl Iterator<Item = &str> {
+		self.subfields
+			.iter()
+			.filter(move |subfield| subfield.code == code)
+			.map(|subfield| subfield.value.as_str())
+	}
+}
+
+/// Decodes text from a record, using leader position 9 to tell MARC-8 from UTF-8.
+pub(crate) fn decode(
+	leader: &Leader,
+	options: ParseOptions,
+	tag: &str,
+	data: &[u8],
+) -> Result<String, ParseError> {
+	match leader.character_coding_scheme {
+		CharacterCodingScheme::Marc8 => Ok(marc8::decode_marc8(data)),
+		CharacterCodingScheme::Ucs if options.lossy => {
+			Ok(String::from_utf8_lossy(data).into_owned())
+		}
+		CharacterCodingScheme::Ucs => {
+			String::from_utf8(data.to_vec()).map_err(|_| ParseError::InvalidUtf8 {
+				tag: tag.to_string(),
+			})
+		}
+	}
+}
+
+/// Strips the ISBD punctuation catalogers put between elements, e.g. `Smith, John,` becomes `Smith, John`.
+/// A final period is kept, since it's often part of an abbreviation like `ed.`.
+pub(crate) fn trim_punctuation(value: &str) -> String {
+	value
+		.trim_end_matches(|c: char| {
+			c.is_whitespace() || matches!(c, '/' | ':' | ';' | '=' | ',' | '+')
+		})
+		.trim_start()
+		.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn splits_indicators_and_subfields() {
+		let field = data_field("10$aThe great gatsby /$cF. Scott Fitzgerald.");
+		assert_eq!(field.indicators, *b"10");
+		assert_eq!(
+			field.subfields,
+			[
+				Subfield {
+					code: b'a',
+					value: "The great gatsby /".to_string()
+				},
+				Subfield {
+					code: b'c',
+					value: "F. Scott Fitzgerald.".to_string()
+				}
+			]
+		);
+	}
+
+	#[test]
+	fn keeps_repeated_subfields_in_order() {
+		let field = data_field(" 0$aPhilosophy$xHistory$xSources.");
+		assert_eq!(field.indicators, *b" 0");
+		assert_eq!(
+			field.subfields(b'x').collect::<Vec<_>>(),
+			["History", "Sources."]
+		);
+	}
+
+	#[test]
+	fn missing_indicators_are_blank() {
+		assert_eq!(data_field("$aNo indicators").indicators, *b"  ");
+		assert_eq!(data_field("").indicators, *b"  ");
+	}
+
+	#[test]
+	fn trims_subfield_whitespace_when_asked() {
+		let leader = Leader::from_bytes(b"00000nam a2200000   4500").unwrap();
+		let data = b"10\x1fa The great gatsby  \x1fc\tF. Scott Fitzgerald. ";
+		let untrimmed = Field::parse(&leader, ParseOptions::default(), "245", data).unwrap();
+		assert_eq!(untrimmed.subfield(b'a'), Some(" The great gatsby  "));
+		let options = ParseOptions {
+			trim_subfield_whitespace: true,
+			..ParseOptions::default()
+		};
+		let trimmed = Field::parse(&leader, options, "245", data).unwrap();
+		assert_eq!(trimmed.indicators, *b"10");
+		assert_eq!(trimmed.subfield(b'a'), Some("The great gatsby"));
+		assert_eq!(trimmed.subfield(b'c'), Some("F. Scott Fitzgerald."));
+	}
+
+	#[test]
+	fn trims_isbd_punctuation() {
+		assert_eq!(trim_punctuation("The great gatsby /"), "The great gatsby");
+		assert_eq!(trim_punctuation("New York :"), "New York");
+		assert_eq!(trim_punctuation("Smith, John,"), "Smith, John");
+		assert_eq!(trim_punctuation("2nd ed."), "2nd ed.");
+	}
+}
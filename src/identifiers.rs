@@ -0,0 +1,398 @@
+//! Standard numbers that identify the resource: ISBNs and the like.
+
+use crate::blank_as_hash;
+use crate::field::{Field, Subfield};
+use num_enum::FromPrimitive;
+
+/// A Library of Congress Control Number, from field 010.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd010.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Lccn {
+	/// The LCCN, normalized as described at <https://www.loc.gov/marc/lccn-namespace.html>, e.g. `86104385`.
+	/// Empty if the field only has canceled LCCNs.
+	pub number: String,
+	/// The LCCN as it appears in subfield `a`, blanks and all, e.g. `   86104385 `.
+	pub raw: String,
+	/// Canceled or invalid LCCNs, normalized, from subfield `z`.
+	pub canceled: Vec<String>,
+}
+
+impl Lccn {
+	/// Reads a field 010.
+	pub(crate) fn from_field_010(field: &Field) -> Lccn {
+		let raw = field.subfield(b'a').unwrap_or_default().to_string();
+		Lccn {
+			number: normalize_lccn(&raw),
+			raw,
+			canceled: field.subfields(b'z').map(normalize_lccn).collect(),
+		}
+	}
+}
+
+/// An International Standard Book Number, from field 020.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd020.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Isbn {
+	/// The ISBN as it appears in the record, e.g. `0-684-80152-3` or `9780743273565 (pbk.)`.
+	pub value: String,
+	/// The ISBN with hyphens and any trailing text removed, e.g. `0684801523`.
+	pub normalized: String,
+	/// Qualifying information such as `hardcover`, from subfield `q`.
+	/// Repeated `q` subfields are joined with `; `.
+	pub qualifier: Option<String>,
+	/// Whether the ISBN is canceled or invalid (subfield `z`) rather than valid (subfield `a`).
+	pub canceled: bool,
+}
+
+impl Isbn {
+	/// Reads every ISBN in a field 020, in the order they appear.
+	pub(crate) fn from_field_020(field: &Field) -> Vec<Isbn> {
+		let qualifiers: Vec<&str> = field.subfields(b'q').collect();
+		let qualifier = (!qualifiers.is_empty()).then(|| qualifiers.join("; "));
+		field
+			.subfields
+			.iter()
+			.filter(|Subfield { code, .. }| matches!(code, b'a' | b'z'))
+			.map(|Subfield { code, value }| Isbn {
+				value: value.clone(),
+				normalized: normalize_isbn(value),
+				qualifier: qualifier.clone(),
+				canceled: *code == b'z',
+			})
+			.collect()
+	}
+}
+
+/// An International Standard Serial Number, from field 022.
+///
+/// Values are kept as written, usually in the hyphenated `NNNN-NNNN` form; check digits aren't verified.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd022.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Issn {
+	/// The ISSN, from subfield `a`. Empty if the field only has incorrect or canceled ISSNs.
+	pub value: String,
+	/// Incorrect (subfield `y`) and canceled (subfield `z`) ISSNs, in order.
+	pub canceled: Vec<String>,
+	/// The linking ISSN (ISSN-L), which groups the different media versions of a serial, from subfield `l`.
+	pub linking: Option<String>,
+}
+
+impl Issn {
+	/// Reads a field 022.
+	pub(crate) fn from_field_022(field: &Field) -> Issn {
+		Issn {
+			value: field.subfield(b'a').unwrap_or_default().to_string(),
+			canceled: field
+				.subfields
+				.iter()
+				.filter(|Subfield { code, .. }| matches!(code, b'y' | b'z'))
+				.map(|Subfield { value, .. }| value.clone())
+				.collect(),
+			linking: field.subfield(b'l').map(str::to_string),
+		}
+	}
+}
+
+/// A standard number without a field of its own, such as a UPC or DOI, from field 024.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd024.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct StandardIdentifier {
+	/// What kind of number this is, from the first indicator.
+	pub kind: IdentifierKind,
+	/// The number, e.g. `093624526722`.
+	pub value: String,
+	/// The kind of number when `kind` is [`IdentifierKind::SourceInSubfield2`], e.g. `doi`, from subfield `2`.
+	pub source: Option<String>,
+	/// Whether the number is canceled or invalid (subfield `z`) rather than valid (subfield `a`).
+	pub canceled: bool,
+}
+
+impl StandardIdentifier {
+	/// Reads every identifier in a field 024, in the order they appear.
+	pub(crate) fn from_field_024(field: &Field) -> Vec<StandardIdentifier> {
+		let kind = IdentifierKind::from_primitive(blank_as_hash(field.indicators[0]));
+		let source = field.subfield(b'2').map(str::to_string);
+		field
+			.subfields
+			.iter()
+			.filter(|Subfield { code, .. }| matches!(code, b'a' | b'z'))
+			.map(|Subfield { code, value }| StandardIdentifier {
+				kind,
+				value: value.trim().to_string(),
+				source: source.clone(),
+				canceled: *code == b'z',
+			})
+			.collect()
+	}
+}
+
+/// What kind of number a field 024 holds.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum IdentifierKind {
+	/// 0 - International Standard Recording Code
+	Isrc = b'0',
+	/// 1 - Universal Product Code
+	Upc = b'1',
+	/// 2 - International Standard Music Number
+	Ismn = b'2',
+	/// 3 - International Article Number (EAN)
+	Ean = b'3',
+	/// 4 - Serial Item and Contribution Identifier
+	Sici = b'4',
+	/// 7 - Source specified in subfield $2
+	SourceInSubfield2 = b'7',
+	/// 8 - Unspecified type of standard number or code
+	Unspecified = b'8',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// A number a publisher or distributor assigned to the resource, such as a record label's issue number, from field 028.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd028.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PublisherNumber {
+	/// The number, e.g. `SL 6098`, from subfield `a`.
+	pub number: String,
+	/// Who assigned the number, e.g. `Deutsche Grammophon`, from subfield `b`.
+	pub source: Option<String>,
+	/// What kind of number this is, from the first indicator.
+	pub kind: PublisherNumberKind,
+}
+
+impl PublisherNumber {
+	/// Reads a field 028.
+	pub(crate) fn from_field_028(field: &Field) -> PublisherNumber {
+		PublisherNumber {
+			number: field.subfield(b'a').unwrap_or_default().trim().to_string(),
+			source: field.subfield(b'b').map(|source| source.trim().to_string()),
+			kind: PublisherNumberKind::from_primitive(blank_as_hash(field.indicators[0])),
+		}
+	}
+}
+
+/// What kind of number a field 028 holds.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum PublisherNumberKind {
+	/// 0 - Issue number, for sound recordings
+	IssueNumber = b'0',
+	/// 1 - Matrix number, for sound recordings
+	MatrixNumber = b'1',
+	/// 2 - Plate number, for printed music
+	PlateNumber = b'2',
+	/// 3 - Other music publisher number
+	OtherMusicNumber = b'3',
+	/// 4 - Video recording publisher number
+	VideoRecordingNumber = b'4',
+	/// 5 - Other publisher number
+	OtherPublisherNumber = b'5',
+	/// 6 - Distributor number
+	DistributorNumber = b'6',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// A control number the record has in another system, such as an OCLC number, from field 035.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd035.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SystemControlNumber {
+	/// The code of the organization the number belongs to, e.g. `OCoLC`, from the parentheses in front of the number.
+	pub prefix: Option<String>,
+	/// The number itself, e.g. `14919759`.
+	pub number: String,
+	/// Whether the number is canceled or invalid (subfield `z`) rather than valid (subfield `a`).
+	pub canceled: bool,
+}
+
+impl SystemControlNumber {
+	/// Reads every control number in a field 035, in the order they appear.
+	pub(crate) fn from_field_035(field: &Field) -> Vec<SystemControlNumber> {
+		field
+			.subfields
+			.iter()
+			.filter(|Subfield { code, .. }| matches!(code, b'a' | b'z'))
+			.map(|Subfield { code, value }| {
+				let value = value.trim();
+				let (prefix, number) = value
+					.strip_prefix('(')
+					.and_then(|rest| rest.split_once(')'))
+					.map_or((None, value), |(prefix, number)| {
+						(Some(prefix.to_string()), number)
+					});
+				SystemControlNumber {
+					prefix,
+					number: number.trim().to_string(),
+					canceled: *code == b'z',
+				}
+			})
+			.collect()
+	}
+}
+
+/// Normalizes an LCCN: blanks are removed, as is anything after a slash,
+/// and a hyphen is dropped with the serial number after it zero-padded to six digits.
+fn normalize_lccn(value: &str) -> String {
+	let value: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+	let value = value.split('/').next().unwrap_or_default();
+	match value.split_once('-') {
+		Some((prefix, serial)) => format!("{prefix}{serial:0>6}"),
+		None => value.to_string(),
+	}
+}
+
+/// Keeps the digits and check character of the first word of an ISBN.
+fn normalize_isbn(value: &str) -> String {
+	value
+		.split_whitespace()
+		.next()
+		.unwrap_or_default()
+		.chars()
+		.filter(|c| c.is_ascii_digit() || matches!(c, 'x' | 'X'))
+		.map(|c| c.to_ascii_uppercase())
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn reads_valid_and_canceled_isbns() {
+		let isbns =
+			Isbn::from_field_020(&data_field("  $a0-684-80152-3$qhardcover$z0-684-80152-x"));
+		assert_eq!(
+			isbns,
+			[
+				Isbn {
+					value: "0-684-80152-3".to_string(),
+					normalized: "0684801523".to_string(),
+					qualifier: Some("hardcover".to_string()),
+					canceled: false,
+				},
+				Isbn {
+					value: "0-684-80152-x".to_string(),
+					normalized: "068480152X".to_string(),
+					qualifier: Some("hardcover".to_string()),
+					canceled: true,
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn reads_issn() {
+		let issn = Issn::from_field_022(&data_field(
+			"0 $a0046-225X$y0046-2254$z0046-2250$l0046-225X",
+		));
+		assert_eq!(
+			issn,
+			Issn {
+				value: "0046-225X".to_string(),
+				canceled: vec!["0046-2254".to_string(), "0046-2250".to_string()],
+				linking: Some("0046-225X".to_string()),
+			}
+		);
+	}
+
+	#[test]
+	fn normalizes_isbn_with_trailing_qualifier() {
+		let isbns = Isbn::from_field_020(&data_field("  $a9780743273565 (pbk.)"));
+		assert_eq!(isbns[0].normalized, "9780743273565");
+		assert_eq!(isbns[0].qualifier, None);
+	}
+
+	#[test]
+	fn normalizes_lccn() {
+		let lccn = Lccn::from_field_010(&data_field("  $a   86104385 $zn 78890351 "));
+		assert_eq!(
+			lccn,
+			Lccn {
+				number: "86104385".to_string(),
+				raw: "   86104385 ".to_string(),
+				canceled: vec!["n78890351".to_string()],
+			}
+		);
+		assert_eq!(normalize_lccn("75-425165//r75"), "75425165");
+		assert_eq!(normalize_lccn("n78-89035"), "n78089035");
+	}
+
+	#[test]
+	fn splits_system_control_number_prefix() {
+		let numbers = SystemControlNumber::from_field_035(&data_field(
+			"  $a(OCoLC)14919759$z(OCoLC)ocm00000001",
+		));
+		assert_eq!(
+			numbers,
+			[
+				SystemControlNumber {
+					prefix: Some("OCoLC".to_string()),
+					number: "14919759".to_string(),
+					canceled: false,
+				},
+				SystemControlNumber {
+					prefix: Some("OCoLC".to_string()),
+					number: "ocm00000001".to_string(),
+					canceled: true,
+				},
+			]
+		);
+		let bare = SystemControlNumber::from_field_035(&data_field("  $a123456"));
+		assert_eq!(bare[0].prefix, None);
+		assert_eq!(bare[0].number, "123456");
+	}
+
+	#[test]
+	fn reads_standard_identifiers() {
+		let identifiers = StandardIdentifier::from_field_024(&data_field("1 $a093624526722"));
+		assert_eq!(
+			identifiers,
+			[StandardIdentifier {
+				kind: IdentifierKind::Upc,
+				value: "093624526722".to_string(),
+				source: None,
+				canceled: false,
+			}]
+		);
+		let doi =
+			StandardIdentifier::from_field_024(&data_field("7 $a10.1000/182$2doi$z10.1000/181"));
+		assert_eq!(doi[0].kind, IdentifierKind::SourceInSubfield2);
+		assert_eq!(doi[0].source.as_deref(), Some("doi"));
+		assert!(doi[1].canceled);
+	}
+
+	#[test]
+	fn reads_plate_number() {
+		assert_eq!(
+			PublisherNumber::from_field_028(&data_field(
+				"22$aB. & H. 8797$bBreitkopf & H\u{e4}rtel"
+			)),
+			PublisherNumber {
+				number: "B. & H. 8797".to_string(),
+				source: Some("Breitkopf & H\u{e4}rtel".to_string()),
+				kind: PublisherNumberKind::PlateNumber,
+			}
+		);
+	}
+}
@@ -0,0 +1,106 @@
+//! The languages of a resource.
+
+use crate::error::ParseWarning;
+use crate::field::Field;
+use std::default::Default;
+
+/// The languages of a resource, from field 041.
+///
+/// Codes are three-letter MARC language codes, e.g. `eng`.
+/// Older records run several codes together in one subfield, e.g. `engfre`; these are split apart.
+/// Whitespace within a subfield is ignored. A subfield that doesn't split into whole codes, e.g. `engfr`,
+/// keeps the whole codes it has and is reported as a [`ParseWarning::MalformedLanguageCode`].
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd041.html>
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct LanguageInfo {
+	/// The languages of the text, sound track or other content, from subfield `a`.
+	pub text: Vec<String>,
+	/// The languages of the original, if the resource is a translation, from subfield `h`.
+	pub original: Vec<String>,
+	/// The languages of subtitles or captions, from subfield `j`.
+	pub subtitles: Vec<String>,
+	/// Whether the resource is or includes a translation, from the first indicator.
+	pub translation: bool,
+}
+
+impl LanguageInfo {
+	/// Adds the languages of a field 041, pushing a warning for each subfield that doesn't split into whole codes.
+	pub(crate) fn add_field_041(&mut self, field: &Field, warnings: &mut Vec<ParseWarning>) {
+		let mut codes = |code| {
+			let mut codes = Vec::new();
+			for value in field.subfields(code) {
+				let chars: Vec<char> = value.chars().filter(|c| !c.is_whitespace()).collect();
+				let chunks = chars.chunks_exact(3);
+				if !chunks.remainder().is_empty() {
+					warnings.push(ParseWarning::MalformedLanguageCode {
+						code,
+						found: value.to_string(),
+					});
+				}
+				codes.extend(chunks.map(|chunk| chunk.iter().collect::<String>()));
+			}
+			codes
+		};
+		self.text.extend(codes(b'a'));
+		self.original.extend(codes(b'h'));
+		self.subtitles.extend(codes(b'j'));
+		self.translation |= field.indicators[0] == b'1';
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn splits_concatenated_codes() {
+		let mut languages = LanguageInfo::default();
+		let mut warnings = Vec::new();
+		languages.add_field_041(&data_field("1 $aengfre$hger$jspa$jita"), &mut warnings);
+		assert!(warnings.is_empty());
+		assert_eq!(
+			languages,
+			LanguageInfo {
+				text: vec!["eng".to_string(), "fre".to_string()],
+				original: vec!["ger".to_string()],
+				subtitles: vec!["spa".to_string(), "ita".to_string()],
+				translation: true,
+			}
+		);
+	}
+
+	#[test]
+	fn not_a_translation_by_default() {
+		let mut languages = LanguageInfo::default();
+		languages.add_field_041(&data_field("0 $aeng$afre"), &mut Vec::new());
+		assert_eq!(languages.text, ["eng", "fre"]);
+		assert!(!languages.translation);
+	}
+
+	#[test]
+	fn warns_about_partial_codes_and_ignores_inner_whitespace() {
+		let mut languages = LanguageInfo::default();
+		let mut warnings = Vec::new();
+		languages.add_field_041(&data_field("0 $aengfr$heng fre$jg"), &mut warnings);
+		assert_eq!(languages.text, ["eng"]);
+		assert_eq!(languages.original, ["eng", "fre"]);
+		assert!(languages.subtitles.is_empty());
+		assert_eq!(
+			warnings,
+			[
+				ParseWarning::MalformedLanguageCode {
+					code: b'a',
+					found: "engfr".to_string()
+				},
+				ParseWarning::MalformedLanguageCode {
+					code: b'j',
+					found: "g".to_string()
+				},
+			]
+		);
+	}
+}
@@ -0,0 +1,394 @@
+//! The 24-byte leader at the start of every record.
+//!
+//! Original documentation:
+//!
+//! <https://www.loc.gov/marc/bibliographic/concise/bdleader.html>
+
+use crate::error::ParseError;
+use crate::{blank_as_hash, parse_number, ParseOptions};
+use num_enum::TryFromPrimitive;
+
+/// The leader of a record, which describes how to read the rest of it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Leader {
+	/// The length of the whole record, including the leader and record terminator.
+	/// Positions 0-4.
+	///
+	/// 0 for records longer than 99999 bytes, whose end is found by their record terminator instead.
+	pub record_length: usize,
+	/// The relationship of the record to a file, for file maintenance.
+	/// Position 5.
+	pub record_status: RecordStatus,
+	/// The type of material the record describes.
+	/// Position 6.
+	pub type_of_record: TypeOfRecord,
+	/// The bibliographic level of the record.
+	/// Position 7.
+	pub bibliographic_level: BibliographicLevel,
+	/// The character set the record's text is in.
+	/// Position 9.
+	///
+	/// A value other than blank or `a` is read as UCS/Unicode; [`Marc::from_bytes`](crate::Marc::from_bytes) warns about it.
+	pub character_coding_scheme: CharacterCodingScheme,
+	/// The number of indicators in each data field, always 2 in MARC21.
+	/// Position 10.
+	pub indicator_count: u8,
+	/// The number of bytes used for each subfield code (including the delimiter), always 2 in MARC21.
+	/// Position 11.
+	pub subfield_code_count: u8,
+	/// The offset of the first data field from the start of the record.
+	/// Positions 12-16.
+	pub base_address_of_data: usize,
+	/// How complete the record is.
+	/// Position 17.
+	pub encoding_level: EncodingLevel,
+	/// The cataloging rules the record follows.
+	/// Position 18.
+	pub descriptive_cataloging_form: DescriptiveCatalogingForm,
+	/// Whether the record describes a set or part of one.
+	/// Position 19.
+	pub multipart_resource_record_level: MultipartResourceRecordLevel,
+}
+
+impl Leader {
+	/// Parses a leader.
+	///
+	/// Positions 20-23 must be `4500`, the entry map shared by all MARC21 records.
+	pub fn from_bytes(bytes: &[u8; 24]) -> Result<Leader, ParseError> {
+		Leader::from_bytes_with_options(bytes, ParseOptions::default())
+	}
+
+	/// Parses a leader, leniently if `options.lossy` is set.
+	///
+	/// When lossy, coded positions that can't be decoded become `NotCoded`,
+	/// an unreadable record length becomes 0, the indicator and subfield code counts fall back to 2,
+	/// and the entry map isn't checked. An unreadable base address of data is still an error,
+	/// since the fields can't be found without it.
+	pub fn from_bytes_with_options(
+		bytes: &[u8; 24],
+		options: ParseOptions,
+	) -> Result<Leader, ParseError> {
+		fn code<T: TryFromPrimitive<Primitive = u8>>(
+			bytes: &[u8; 24],
+			position: usize,
+			options: ParseOptions,
+		) -> Result<T, ParseError> {
+			let invalid = ParseError::InvalidLeader {
+				position,
+				found: bytes[position],
+			};
+			// `|` is never valid in a leader; it only stands in for codes the lossy path couldn't read.
+			let byte = blank_as_hash(bytes[position]);
+			match T::try_from_primitive(byte) {
+				Ok(code) if byte != b'|' => Ok(code),
+				_ if options.lossy => T::try_from_primitive(b'|').map_err(|_| invalid),
+				_ => Err(invalid),
+			}
+		}
+		fn digit(
+			bytes: &[u8; 24],
+			position: usize,
+			options: ParseOptions,
+		) -> Result<u8, ParseError> {
+			match bytes[position] {
+				digit @ b'0'..=b'9' => Ok(digit - b'0'),
+				_ if options.lossy => Ok(2),
+				found => Err(ParseError::InvalidLeader { position, found }),
+			}
+		}
+
+		if &bytes[20..24] != b"4500" && !options.lossy {
+			return Err(ParseError::InvalidEntryMap(bytes[20..24].to_vec()));
+		}
+		let record_length = match parse_number(&bytes[0..5], "record length") {
+			Err(_) if options.lossy => 0,
+			record_length => record_length?,
+		};
+		Ok(Leader {
+			record_length,
+			record_status: code(bytes, 5, options)?,
+			type_of_record: code(bytes, 6, options)?,
+			bibliographic_level: code(bytes, 7, options)?,
+			character_coding_scheme: CharacterCodingScheme::try_from_primitive(blank_as_hash(
+				bytes[9],
+			))
+			.unwrap_or(CharacterCodingScheme::Ucs),
+			indicator_count: digit(bytes, 10, options)?,
+			subfield_code_count: digit(bytes, 11, options)?,
+			base_address_of_data: parse_number(&bytes[12..17], "base address of data")?,
+			encoding_level: code(bytes, 17, options)?,
+			descriptive_cataloging_form: code(bytes, 18, options)?,
+			multipart_resource_record_level: code(bytes, 19, options)?,
+		})
+	}
+}
+
+/// The relationship of the record to a file.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum RecordStatus {
+	/// a - Increase in encoding level
+	IncreaseInEncodingLevel = b'a',
+	/// c - Corrected or revised
+	Corrected = b'c',
+	/// d - Deleted
+	Deleted = b'd',
+	/// n - New
+	New = b'n',
+	/// p - Increase in encoding level from prepublication
+	IncreaseFromPrepublication = b'p',
+	/// | - No attempt to code, standing in for a value lossy parsing couldn't read
+	NotCoded = b'|',
+}
+
+/// The type of material the record describes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum TypeOfRecord {
+	/// a - Language material
+	LanguageMaterial = b'a',
+	/// c - Notated music
+	NotatedMusic = b'c',
+	/// d - Manuscript notated music
+	ManuscriptNotatedMusic = b'd',
+	/// e - Cartographic material
+	Cartographic = b'e',
+	/// f - Manuscript cartographic material
+	ManuscriptCartographic = b'f',
+	/// g - Projected medium
+	ProjectedMedium = b'g',
+	/// i - Nonmusical sound recording
+	NonmusicalSoundRecording = b'i',
+	/// j - Musical sound recording
+	MusicalSoundRecording = b'j',
+	/// k - Two-dimensional nonprojectable graphic
+	Graphic = b'k',
+	/// m - Computer file
+	ComputerFile = b'm',
+	/// o - Kit
+	Kit = b'o',
+	/// p - Mixed materials
+	MixedMaterials = b'p',
+	/// r - Three-dimensional artifact or naturally occurring object
+	ThreeDimensional = b'r',
+	/// t - Manuscript language material
+	ManuscriptLanguageMaterial = b't',
+	/// | - No attempt to code, standing in for a value lossy parsing couldn't read
+	NotCoded = b'|',
+}
+
+/// The bibliographic level of the record.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum BibliographicLevel {
+	/// a - Monographic component part
+	MonographicComponentPart = b'a',
+	/// b - Serial component part
+	SerialComponentPart = b'b',
+	/// c - Collection
+	Collection = b'c',
+	/// d - Subunit
+	Subunit = b'd',
+	/// i - Integrating resource
+	IntegratingResource = b'i',
+	/// m - Monograph/Item
+	Monograph = b'm',
+	/// s - Serial
+	Serial = b's',
+	/// | - No attempt to code, standing in for a value lossy parsing couldn't read
+	NotCoded = b'|',
+}
+
+/// The character set the record's text is in.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum CharacterCodingScheme {
+	/// # - MARC-8, decoded with [`decode_marc8`](crate::marc8::decode_marc8)
+	Marc8 = b'#',
+	/// a - UCS/Unicode, encoded as UTF-8
+	Ucs = b'a',
+}
+
+/// How complete the record is.
+///
+/// Besides the MARC 21 levels, this has the uppercase levels OCLC uses in WorldCat records,
+/// which are common enough in the wild that a strict parse shouldn't reject them.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum EncodingLevel {
+	/// # - Full level
+	Full = b'#',
+	/// 1 - Full level, material not examined
+	FullNotExamined = b'1',
+	/// 2 - Less-than-full level, material not examined
+	LessThanFullNotExamined = b'2',
+	/// 3 - Abbreviated level
+	Abbreviated = b'3',
+	/// 4 - Core level
+	Core = b'4',
+	/// 5 - Partial (preliminary) level
+	Partial = b'5',
+	/// 7 - Minimal level
+	Minimal = b'7',
+	/// 8 - Prepublication level
+	Prepublication = b'8',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Not applicable
+	NotApplicable = b'z',
+	/// I - Full level, input by an OCLC participant (OCLC)
+	OclcFull = b'I',
+	/// J - Deleted record (OCLC)
+	OclcDeleted = b'J',
+	/// K - Less-than-full level, input by an OCLC participant (OCLC)
+	OclcLessThanFull = b'K',
+	/// L - Full level, added from a batch process (OCLC)
+	OclcBatchFull = b'L',
+	/// M - Less-than-full level, added from a batch process (OCLC)
+	OclcBatchLessThanFull = b'M',
+	/// | - No attempt to code, standing in for a value lossy parsing couldn't read
+	NotCoded = b'|',
+}
+
+/// The cataloging rules the record follows.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum DescriptiveCatalogingForm {
+	/// # - Non-ISBD
+	NonIsbd = b'#',
+	/// a - AACR 2
+	Aacr2 = b'a',
+	/// c - ISBD punctuation omitted
+	IsbdPunctuationOmitted = b'c',
+	/// i - ISBD punctuation included
+	IsbdPunctuationIncluded = b'i',
+	/// n - Non-ISBD punctuation omitted
+	NonIsbdPunctuationOmitted = b'n',
+	/// u - Unknown
+	Unknown = b'u',
+	/// | - No attempt to code, standing in for a value lossy parsing couldn't read
+	NotCoded = b'|',
+}
+
+/// Whether the record describes a set or part of one.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum MultipartResourceRecordLevel {
+	/// # - Not specified or not applicable
+	NotSpecified = b'#',
+	/// a - Set
+	Set = b'a',
+	/// b - Part with independent title
+	PartWithIndependentTitle = b'b',
+	/// c - Part with dependent title
+	PartWithDependentTitle = b'c',
+	/// | - No attempt to code, standing in for a value lossy parsing couldn't read
+	NotCoded = b'|',
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parses_leader() {
+		let leader = Leader::from_bytes(b"01142cam  2200301 a 4500").unwrap();
+		assert_eq!(leader.record_length, 1142);
+		assert_eq!(leader.record_status, RecordStatus::Corrected);
+		assert_eq!(leader.type_of_record, TypeOfRecord::LanguageMaterial);
+		assert_eq!(leader.bibliographic_level, BibliographicLevel::Monograph);
+		assert_eq!(leader.character_coding_scheme, CharacterCodingScheme::Marc8);
+		assert_eq!(leader.indicator_count, 2);
+		assert_eq!(leader.subfield_code_count, 2);
+		assert_eq!(leader.base_address_of_data, 301);
+		assert_eq!(leader.encoding_level, EncodingLevel::Full);
+		assert_eq!(
+			leader.descriptive_cataloging_form,
+			DescriptiveCatalogingForm::Aacr2
+		);
+		assert_eq!(
+			leader.multipart_resource_record_level,
+			MultipartResourceRecordLevel::NotSpecified
+		);
+	}
+
+	#[test]
+	fn parses_oclc_encoding_levels() {
+		for (code, level) in [
+			(b'I', EncodingLevel::OclcFull),
+			(b'J', EncodingLevel::OclcDeleted),
+			(b'K', EncodingLevel::OclcLessThanFull),
+			(b'L', EncodingLevel::OclcBatchFull),
+			(b'M', EncodingLevel::OclcBatchLessThanFull),
+		] {
+			let mut bytes = *b"01142cam  2200301 a 4500";
+			bytes[17] = code;
+			assert_eq!(Leader::from_bytes(&bytes).unwrap().encoding_level, level);
+		}
+		assert!(matches!(
+			Leader::from_bytes(b"01142cam  2200301Xa 4500"),
+			Err(ParseError::InvalidLeader {
+				position: 17,
+				found: b'X'
+			})
+		));
+	}
+
+	#[test]
+	fn rejects_bad_entry_map() {
+		assert!(matches!(
+			Leader::from_bytes(b"01142cam  2200301 a 4600"),
+			Err(ParseError::InvalidEntryMap(_))
+		));
+	}
+
+	#[test]
+	fn unknown_character_coding_scheme_is_unicode() {
+		let leader = Leader::from_bytes(b"01142cam z2200301 a 4500").unwrap();
+		assert_eq!(leader.character_coding_scheme, CharacterCodingScheme::Ucs);
+	}
+
+	#[test]
+	fn lossy_leader_falls_back_to_not_coded() {
+		let lossy = ParseOptions {
+			lossy: true,
+			..ParseOptions::default()
+		};
+		let leader = Leader::from_bytes_with_options(b"0114?cxm  2x00301 7 4600", lossy).unwrap();
+		assert_eq!(leader.record_length, 0);
+		assert_eq!(leader.type_of_record, TypeOfRecord::NotCoded);
+		assert_eq!(leader.bibliographic_level, BibliographicLevel::Monograph);
+		assert_eq!(leader.subfield_code_count, 2);
+		assert_eq!(leader.base_address_of_data, 301);
+		assert_eq!(
+			leader.descriptive_cataloging_form,
+			DescriptiveCatalogingForm::NotCoded
+		);
+		assert!(matches!(
+			Leader::from_bytes_with_options(b"01142cam  2200x01 a 4500", lossy),
+			Err(ParseError::InvalidNumber { .. })
+		));
+	}
+
+	#[test]
+	fn rejects_fill_character() {
+		assert!(matches!(
+			Leader::from_bytes(b"01142c|m  2200301 a 4500"),
+			Err(ParseError::InvalidLeader {
+				position: 6,
+				found: b'|'
+			})
+		));
+	}
+
+	#[test]
+	fn rejects_unknown_code() {
+		assert!(matches!(
+			Leader::from_bytes(b"01142cxm  2200301 a 4500"),
+			Err(ParseError::InvalidLeader {
+				position: 6,
+				found: b'x'
+			})
+		));
+	}
+}
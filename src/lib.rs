@@ -2,23 +2,96 @@
 #![feature(proc_macro_hygiene)]
 //! A library for converting MARC21 files into an ergonomic struct for easier processing.
 
+#[cfg(all(feature = "serde_support", feature = "serde_names"))]
+compile_error!(
+	"the `serde_support` and `serde_names` features serialize coded enums differently; enable only one of them"
+);
+
 #[macro_use]
 extern crate num_enum;
 pub mod additional_material_characteristics;
-use chrono::NaiveDateTime;
-
+pub mod cataloging;
+pub mod classification;
+pub mod description;
+pub mod directory;
+pub mod error;
+pub mod field;
+pub mod identifiers;
+pub mod languages;
+pub mod leader;
+pub mod locations;
+pub mod marc8;
+pub mod marc_ref;
+pub mod material_characteristics;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+pub mod names;
+pub mod notes;
+pub mod physical_characteristics;
+pub mod publication;
+pub mod reader;
+pub mod records;
+#[cfg(feature = "search")]
+pub mod search;
+#[cfg(feature = "tokio")]
+pub mod stream;
+pub mod subjects;
 #[cfg(test)]
-mod tests {
-	#[test]
-	fn it_works() {
-		let result = 2 + 2;
-		assert_eq!(result, 4);
-	}
+mod test_utils;
+pub mod titles;
+use additional_material_characteristics::{AdditionalMaterialCharacteristics, NatureOfContents};
+use cataloging::CatalogingSource;
+use chrono::{NaiveDateTime, Timelike};
+use classification::{CallNumber, DeweyNumber};
+use description::{PhysicalDescription, RdaTerm};
+use error::{ParseError, ParseWarning};
+use field::{decode, Field};
+use identifiers::{Isbn, Issn, Lccn, PublisherNumber, StandardIdentifier, SystemControlNumber};
+use languages::LanguageInfo;
+use leader::{CharacterCodingScheme, Leader};
+use locations::ElectronicLocation;
+use marc_ref::MarcRef;
+use material_characteristics::MaterialCharacteristics;
+use names::{AddedEntry, MainEntry};
+use notes::{ContentsNote, Summary};
+use num_enum::TryFromPrimitive;
+use physical_characteristics::PhysicalDescription007;
+use publication::{PublicationInfo, PublicationKind};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use subjects::SubjectHeading;
+use titles::{PartInfo, SeriesStatement, TitleStatement, UniformTitle, VaryingTitle};
+
+/// Ends each field, and the directory.
+pub const FIELD_TERMINATOR: u8 = 0x1E;
+/// Introduces each subfield code within a data field.
+pub const SUBFIELD_DELIMITER: u8 = 0x1F;
+/// Ends each record.
+pub const RECORD_TERMINATOR: u8 = 0x1D;
+/// The UTF-8 byte order mark, which some Windows tools put at the start of a file.
+const BYTE_ORDER_MARK: &[u8] = b"\xEF\xBB\xBF";
+
+/// Settings for how strictly records are parsed.
+// Spelled out because num_enum's `Default` derive, which only handles enums, shadows the std one.
+#[derive(Debug, Clone, Copy, std::default::Default, Eq, PartialEq)]
+pub struct ParseOptions {
+	/// Make a best effort instead of failing on bad bytes.
+	///
+	/// Invalid UTF-8 is replaced with U+FFFD, and leader codes that can't be read become `NotCoded`
+	/// (see [`Leader::from_bytes_with_options`]).
+	/// Records whose fields can't be located, e.g. because of a broken directory, are still errors.
+	pub lossy: bool,
+	/// Trim leading and trailing whitespace from data-field subfield values, for display-oriented use.
+	///
+	/// Off by default to keep values byte for byte. Control fields, whose padding marks positions, are never trimmed.
+	pub trim_subfield_whitespace: bool,
 }
 
 /// A struct containing data from a MARC21 file.
 /// Much of the documentation is taken from https://www.loc.gov/marc/bibliographic/
+#[derive(Debug)]
 pub struct Marc {
+	/// The leader of the record, which describes how it is laid out and what kind of material it describes.
+	pub leader: Leader,
 	/// The control number of the record.
 	///
 	/// Original documentation:
@@ -45,6 +118,8 @@ pub struct Marc {
 	///
 	/// 001 = #####9007496
 	/// 003 = DNLM
+	///
+	/// Empty if the record has no 001, which some vendor records omit.
 	pub control_number: String,
 	/// The organization assigning the control number.
 	///
@@ -68,6 +143,8 @@ pub struct Marc {
 	///
 	/// 001 = #####9007496
 	/// 003 = DNLM
+	///
+	/// Empty if the record has no 003.
 	pub control_number_identifier: String,
 	/// The time at which the record was last modified.
 	///
@@ -80,10 +157,2078 @@ pub struct Marc {
 	/// Example
 	///
 	/// 005 = 19940223151047.0
-	pub date_and_time_of_latest_record_transaction: NaiveDateTime,
+	///
+	/// `None` if the record has no 005 or it isn't in the form above.
+	pub date_and_time_of_latest_record_transaction: Option<NaiveDateTime>,
 	/// Encoded information about the nature of the material.
 	///
 	/// The original documentation is quite lengthy; here is the URL: https://www.loc.gov/marc/bibliographic/bd006.html
-	pub additional_material_characteristics:
-		additional_material_characteristics::AdditionalMaterialCharacteristics,
+	///
+	/// `None` if the record has no 006, or its 006 couldn't be decoded.
+	pub additional_material_characteristics: Option<AdditionalMaterialCharacteristics>,
+	/// Coded information about the carrier of the material, such as a disc's size or a file's color content,
+	/// one per field 007, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd007.html
+	///
+	/// 007s for categories of material that aren't modelled yet are left out; they're still in `raw_fields`.
+	pub physical_007: Vec<PhysicalDescription007>,
+	/// Encoded information about the record as a whole, including dates, place of publication and language,
+	/// and the same material-specific values as field 006.
+	///
+	/// The original documentation is quite lengthy; here is the URL: https://www.loc.gov/marc/bibliographic/bd008.html
+	///
+	/// `None` if the record has no 008.
+	/// For visual materials the 008 is still read, but its material-specific positions 18-34 aren't modelled yet,
+	/// so [`MaterialCharacteristics::material`](material_characteristics::MaterialCharacteristics::material) is `None`.
+	pub eight: Option<MaterialCharacteristics>,
+	/// The Library of Congress Control Number of the record, from field 010.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd010.html
+	///
+	/// `None` if the record has no 010.
+	pub lccn: Option<Lccn>,
+	/// The International Standard Book Numbers of the resource, from every field 020, in order.
+	/// Canceled and invalid ISBNs are included, marked as `canceled`.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd020.html
+	pub isbns: Vec<Isbn>,
+	/// The International Standard Serial Numbers of the resource, one per field 022, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd022.html
+	pub issns: Vec<Issn>,
+	/// Other standard numbers of the resource, such as UPCs, ISMNs and DOIs, from every field 024, in order.
+	/// Canceled and invalid numbers are included, marked as `canceled`.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd024.html
+	pub other_identifiers: Vec<StandardIdentifier>,
+	/// Numbers publishers and distributors assigned to the resource, such as issue and plate numbers,
+	/// one per field 028, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd028.html
+	pub publisher_numbers: Vec<PublisherNumber>,
+	/// The record's control numbers in other systems, such as OCLC numbers, from every field 035, in order.
+	/// Canceled and invalid numbers are included, marked as `canceled`.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd035.html
+	pub system_control_numbers: Vec<SystemControlNumber>,
+	/// The agencies that created, transcribed and modified the record, and the description conventions it follows, from field 040.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd040.html
+	///
+	/// `None` if the record has no 040.
+	pub cataloging_source: Option<CatalogingSource>,
+	/// The languages of the resource, its original and its subtitles, from field 041.
+	/// This complements the single language code in the 008.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd041.html
+	///
+	/// Empty if the record has no 041.
+	pub languages: LanguageInfo,
+	/// The geographic area codes of the places the resource is about, e.g. `n-us-ny`, from subfield `a` of field 043.
+	/// The hyphens that pad codes to seven characters are removed.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd043.html
+	pub geographic_area_codes: Vec<String>,
+	/// The Library of Congress call numbers of the resource, one per field 050, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd050.html
+	pub lc_call_numbers: Vec<CallNumber>,
+	/// The Dewey Decimal Classification numbers of the resource, one per field 082, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd082.html
+	pub dewey_numbers: Vec<DeweyNumber>,
+	/// The person, organization or meeting chiefly responsible for the work.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd1xx.html
+	///
+	/// `None` if the record has no main entry, e.g. because the work is entered under its title.
+	pub main_entry: Option<MainEntry>,
+	/// The uniform title of the work, which brings its editions and translations together, from field 240.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd240.html
+	///
+	/// `None` if the record has no 240, which only appears with a main entry.
+	pub uniform_title: Option<UniformTitle>,
+	/// The title of the resource and who is responsible for it.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd245.html
+	///
+	/// `None` if the record has no 245.
+	pub title: Option<TitleStatement>,
+	/// Other titles the resource is known by, such as cover and spine titles, from every field 246, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd246.html
+	pub varying_titles: Vec<VaryingTitle>,
+	/// The edition statements of the resource, e.g. `2nd ed.`, one per field 250, in order.
+	/// Most records have at most one.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd250.html
+	pub editions: Vec<String>,
+	/// Where, by whom and when the resource was published, one per field 260, in order.
+	/// Most records have at most one; RDA records usually record this in field 264 instead.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd260.html
+	pub imprints: Vec<PublicationInfo>,
+	/// The production, publication, distribution, manufacture and copyright statements of the resource,
+	/// one per field 264, in order. RDA records use these instead of field 260.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd264.html
+	pub publications: Vec<(PublicationKind, PublicationInfo)>,
+	/// The extent, dimensions and other physical details of the resource, one per field 300, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd300.html
+	pub physical_description: Vec<PhysicalDescription>,
+	/// The kinds of content the resource has, e.g. `text` or `still image`, one per field 336, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd336.html
+	pub content_types: Vec<RdaTerm>,
+	/// The kinds of device needed to use the resource, e.g. `unmediated` or `computer`, one per field 337, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd337.html
+	pub media_types: Vec<RdaTerm>,
+	/// The kinds of carrier the resource comes on, e.g. `volume` or `online resource`, one per field 338, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd338.html
+	pub carrier_types: Vec<RdaTerm>,
+	/// The series the resource belongs to, as transcribed from it, one per field 490, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd490.html
+	pub series_statements: Vec<SeriesStatement>,
+	/// Free-text notes about the resource, from subfield `a` of every field 500, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd500.html
+	pub general_notes: Vec<String>,
+	/// Notes about bibliographies, discographies and the like in the resource, from subfield `a` of every field 504, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd504.html
+	pub bibliography_notes: Vec<String>,
+	/// The contents of the resource, e.g. its chapters, one per field 505, in order.
+	/// Long contents are often split across several 505s.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd505.html
+	pub contents_notes: Vec<ContentsNote>,
+	/// Summaries, reviews and abstracts of the resource, one per field 520, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd520.html
+	pub summaries: Vec<Summary>,
+	/// The topics the resource is about, one per field 650, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd650.html
+	pub topical_subjects: Vec<SubjectHeading>,
+	/// The places the resource is about, one per field 651, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd651.html
+	pub geographic_subjects: Vec<SubjectHeading>,
+	/// Other people, organizations and meetings associated with the work, such as co-authors, in the order they appear.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd70x75x.html
+	pub added_entries: Vec<AddedEntry>,
+	/// Links to the resource, versions of it and related resources such as cover images, one per field 856, in order.
+	///
+	/// The original documentation is here: https://www.loc.gov/marc/bibliographic/bd856.html
+	pub electronic_locations: Vec<ElectronicLocation>,
+	/// Every field of the record after the leader, in the order of the directory, in a generic form.
+	/// This gives access to fields the typed fields above don't cover, such as local 9XX fields.
+	pub raw_fields: Vec<Field>,
+	/// Problems that were found while parsing the record but didn't stop it from being read.
+	pub warnings: Vec<ParseWarning>,
+}
+
+impl Marc {
+	/// Parses a single MARC21 record.
+	///
+	/// The 24-byte leader gives the base address of data,
+	/// and the directory between the leader and the data gives the tag, length and offset of each field.
+	/// Fields end with a [`FIELD_TERMINATOR`] and the record ends with a [`RECORD_TERMINATOR`].
+	///
+	/// Fields are located through the directory, so a field missing its terminator doesn't disturb the fields after it;
+	/// it's reported in `warnings` instead, as is a missing record terminator.
+	///
+	/// A UTF-8 byte order mark before the leader is skipped.
+	pub fn from_bytes(input: &[u8]) -> Result<Marc, ParseError> {
+		Marc::from_bytes_with_options(input, ParseOptions::default())
+	}
+
+	/// Parses a single MARC21 record, leniently if `options.lossy` is set.
+	pub fn from_bytes_with_options(
+		input: &[u8],
+		options: ParseOptions,
+	) -> Result<Marc, ParseError> {
+		Marc::from_ref(&MarcRef::from_bytes_with_options(input, options)?)
+	}
+
+	/// Parses a single MARC21 record, making a best effort instead of failing on bad bytes.
+	/// Shorthand for [`Marc::from_bytes_with_options`] with `lossy` set.
+	pub fn from_bytes_lossy(input: &[u8]) -> Result<Marc, ParseError> {
+		Marc::from_bytes_with_options(
+			input,
+			ParseOptions {
+				lossy: true,
+				..ParseOptions::default()
+			},
+		)
+	}
+
+	/// Whether the resource contains a bibliography, going by its 504 notes
+	/// or [`NatureOfContents::Bibliographies`](additional_material_characteristics::NatureOfContents::Bibliographies)
+	/// in the 008 or 006 of a book.
+	pub fn has_bibliography(&self) -> bool {
+		let coded = |material: Option<&AdditionalMaterialCharacteristics>| {
+			matches!(
+				material,
+				Some(AdditionalMaterialCharacteristics::Book { nature_of_contents, .. })
+					if nature_of_contents.contains(&NatureOfContents::Bibliographies)
+			)
+		};
+		let fixed_length = self
+			.eight
+			.as_ref()
+			.and_then(|characteristics| characteristics.material.as_ref());
+		!self.bibliography_notes.is_empty()
+			|| coded(fixed_length)
+			|| coded(self.additional_material_characteristics.as_ref())
+	}
+
+	/// The value of the first subfield with the given code in any field with the given tag,
+	/// e.g. `marc.get_subfield("245", b'a')` for the title.
+	pub fn get_subfield(&self, tag: &str, code: u8) -> Option<&str> {
+		self.raw_fields
+			.iter()
+			.filter(|field| field.tag == tag)
+			.find_map(|field| field.subfield(code))
+	}
+
+	/// The values of every subfield with the given code in every field with the given tag, in order.
+	pub fn get_subfields(&self, tag: &str, code: u8) -> Vec<&str> {
+		self.raw_fields
+			.iter()
+			.filter(|field| field.tag == tag)
+			.flat_map(|field| field.subfields(code))
+			.collect()
+	}
+
+	/// The indicators of the first field with the given tag, with blanks as spaces.
+	pub fn field_indicators(&self, tag: &str) -> Option<[u8; 2]> {
+		self.get(tag).map(Field::indicators)
+	}
+
+	/// The first field with the given tag, if there is one.
+	/// Like indexing, but without the panic; see the [`Index`](std::ops::Index) impl.
+	pub fn get(&self, tag: &str) -> Option<&Field> {
+		self.raw_fields.iter().find(|field| field.tag == tag)
+	}
+
+	/// How many times a field with the given tag appears in the record.
+	pub fn count_fields(&self, tag: &str) -> usize {
+		self.raw_fields
+			.iter()
+			.filter(|field| field.tag == tag)
+			.count()
+	}
+
+	/// The tags of every field in the record, e.g. `*b"245"`, for checking many tags in one pass.
+	pub fn tag_set(&self) -> BTreeSet<[u8; 3]> {
+		self.raw_fields
+			.iter()
+			.filter_map(|field| field.tag.as_bytes().try_into().ok())
+			.collect()
+	}
+
+	/// Whether the record has a field with each of the given tags, e.g. `marc.has_all(&["100", "245"])`.
+	///
+	/// This builds a [`Marc::tag_set`] on every call;
+	/// when checking several rules against one record, build the set once and query it instead.
+	pub fn has_all(&self, tags: &[&str]) -> bool {
+		let present = self.tag_set();
+		tags.iter().all(|tag| contains_tag(&present, tag))
+	}
+
+	/// Whether the record has a field with any of the given tags.
+	/// Like [`Marc::has_all`], this builds a [`Marc::tag_set`] on every call.
+	pub fn has_any(&self, tags: &[&str]) -> bool {
+		let present = self.tag_set();
+		tags.iter().any(|tag| contains_tag(&present, tag))
+	}
+
+	/// The languages the resource was originally written in, if it's a translation, e.g. `["rus"]`.
+	///
+	/// These are the 041 `$h` codes, all of them if there are several.
+	/// A record marked as a translation (041 first indicator `1`) without any `$h` falls back to the 008 language,
+	/// the only other language the record gives.
+	/// `None` means the record gives no original language, which usually means the text is in its original language.
+	pub fn original_language(&self) -> Option<Vec<&str>> {
+		if !self.languages.original.is_empty() {
+			return Some(self.languages.original.iter().map(String::as_str).collect());
+		}
+		if !self.languages.translation {
+			return None;
+		}
+		let language = self.eight.as_ref()?.language.as_str();
+		(!language.is_empty()).then(|| vec![language])
+	}
+
+	/// The topical and geographic subject headings as facets: a display form with the subdivisions joined by `--`,
+	/// e.g. `Philosophy -- History`, and a normalized key to group by, e.g. `philosophy history`.
+	///
+	/// The key is lowercase, with punctuation turned into spaces and runs of whitespace collapsed,
+	/// so `Philosophy -- History.` and `Philosophy--History` share one.
+	/// Headings with the same key are only listed once, in the form they first appear.
+	pub fn subject_facets(&self) -> Vec<(String, String)> {
+		let mut seen = HashSet::new();
+		self.topical_subjects
+			.iter()
+			.chain(&self.geographic_subjects)
+			.map(|subject| {
+				let display = subject.heading();
+				let key = facet_key(&display);
+				(display, key)
+			})
+			.filter(|(_, key)| seen.insert(key.clone()))
+			.collect()
+	}
+
+	/// The title proper, 245 `$a`, exactly as it appears in the record, e.g. `The great gatsby /`.
+	///
+	/// Unlike [`Marc::title`], the ISBD punctuation isn't trimmed, and nothing is allocated, so this suits quick listings;
+	/// use `title` for the trimmed title, its remainder or the statement of responsibility.
+	pub fn main_title(&self) -> Option<&str> {
+		self.get_subfield("245", b'a')
+	}
+
+	/// The part or section of a multipart work or set the record describes, from subfields `n` and `p` of field 245.
+	/// `None` if the record has no 245, or its 245 has neither subfield.
+	pub fn part_info(&self) -> Option<&PartInfo> {
+		self.title.as_ref()?.part_info.as_ref()
+	}
+
+	/// Writes the record one subfield per line, tab-delimited, for piping into `grep`, `cut` or `awk`.
+	///
+	/// Each line starts with the tag and which occurrence of the tag it is, counting from 1, so repeated fields stay apart.
+	/// Control fields follow with their value, e.g. `001\t1\t86104385`,
+	/// and data fields with their indicators, subfield code and value, e.g. `650\t2\t 0\ta\tLong Island (N.Y.)`.
+	/// A data field without subfields gets one line, ending after its indicators.
+	/// Backslashes, tabs, newlines and carriage returns are escaped as `\\`, `\t`, `\n` and `\r`.
+	pub fn to_line_mode(&self) -> String {
+		let mut occurrences: HashMap<&str, usize> = HashMap::new();
+		let mut lines = String::new();
+		for field in &self.raw_fields {
+			let occurrence = occurrences.entry(field.tag.as_str()).or_default();
+			*occurrence += 1;
+			let start = format!("{}\t{}", escape_line_mode(&field.tag), occurrence);
+			if let Some(value) = &field.control_value {
+				lines += &format!("{}\t{}\n", start, escape_line_mode(value));
+				continue;
+			}
+			let start = format!(
+				"{}\t{}",
+				start,
+				escape_line_mode(&String::from_utf8_lossy(&field.indicators))
+			);
+			if field.subfields.is_empty() {
+				lines += &format!("{}\n", start);
+			}
+			for subfield in &field.subfields {
+				lines += &format!(
+					"{}\t{}\t{}\n",
+					start,
+					escape_line_mode(&(subfield.code as char).to_string()),
+					escape_line_mode(&subfield.value)
+				);
+			}
+		}
+		lines
+	}
+
+	/// Decodes the fields of a record whose leader and directory have already been parsed.
+	fn from_ref(record: &MarcRef) -> Result<Marc, ParseError> {
+		let input = record.as_bytes();
+		let leader = *record.leader();
+		let options = record.options();
+
+		let mut control_number = None;
+		let mut control_number_identifier = None;
+		let mut date_and_time_of_latest_record_transaction = None;
+		let mut additional_material_characteristics = None;
+		let mut physical_007 = Vec::new();
+		let mut eight = None;
+		let mut lccn = None;
+		let mut isbns = Vec::new();
+		let mut issns = Vec::new();
+		let mut other_identifiers = Vec::new();
+		let mut publisher_numbers = Vec::new();
+		let mut system_control_numbers = Vec::new();
+		let mut cataloging_source = None;
+		let mut languages = LanguageInfo::default();
+		let mut geographic_area_codes = Vec::new();
+		let mut lc_call_numbers = Vec::new();
+		let mut dewey_numbers = Vec::new();
+		let mut main_entry = None;
+		let mut uniform_title = None;
+		let mut title = None;
+		let mut varying_titles = Vec::new();
+		let mut editions = Vec::new();
+		let mut imprints = Vec::new();
+		let mut publications = Vec::new();
+		let mut physical_description = Vec::new();
+		let mut content_types = Vec::new();
+		let mut media_types = Vec::new();
+		let mut carrier_types = Vec::new();
+		let mut series_statements = Vec::new();
+		let mut general_notes = Vec::new();
+		let mut bibliography_notes = Vec::new();
+		let mut contents_notes = Vec::new();
+		let mut summaries = Vec::new();
+		let mut topical_subjects = Vec::new();
+		let mut geographic_subjects = Vec::new();
+		let mut added_entries = Vec::new();
+		let mut electronic_locations = Vec::new();
+		let mut raw_fields = Vec::new();
+		let mut warnings = Vec::new();
+
+		if input.last() != Some(&RECORD_TERMINATOR) {
+			warnings.push(ParseWarning::MissingRecordTerminator);
+		}
+		// A length of 0 means the record was too long to give one, or that it couldn't be read leniently.
+		if leader.record_length != 0 && leader.record_length != input.len() {
+			warnings.push(ParseWarning::RecordLengthMismatch {
+				expected: leader.record_length,
+				found: input.len(),
+			});
+		}
+		if CharacterCodingScheme::try_from_primitive(blank_as_hash(input[9])).is_err() {
+			warnings.push(ParseWarning::UnknownCharacterCodingScheme { found: input[9] });
+		}
+
+		for entry in record.entries() {
+			let tag = entry.tag_str();
+			let data = match record.raw_field(entry) {
+				[data @ .., FIELD_TERMINATOR] => data,
+				data => {
+					warnings.push(ParseWarning::MissingFieldTerminator { tag: tag.clone() });
+					data
+				}
+			};
+
+			if tag.starts_with("00") {
+				// Some control fields are parsed from their bytes below, so this copy is decoded leniently
+				// to avoid rejecting a record that parses fine otherwise.
+				let value = decode(
+					&leader,
+					ParseOptions {
+						lossy: true,
+						..ParseOptions::default()
+					},
+					&tag,
+					data,
+				)?;
+				raw_fields.push(Field::control(&tag, value));
+			}
+
+			match tag.as_str() {
+				"001" => control_number = Some(decode(&leader, options, &tag, data)?),
+				"003" => control_number_identifier = Some(decode(&leader, options, &tag, data)?),
+				"005" => {
+					date_and_time_of_latest_record_transaction =
+						parse_date_and_time(&decode(&leader, options, &tag, data)?)
+				}
+				"006" => {
+					additional_material_characteristics =
+						AdditionalMaterialCharacteristics::from_field_006(data)
+				}
+				"007" => physical_007.extend(PhysicalDescription007::from_field_007(data)),
+				"008" => eight = Some(MaterialCharacteristics::from_field_008(data, &leader)),
+				_ if tag.starts_with("00") => {}
+				_ => {
+					let field = Field::parse(&leader, options, &tag, data)?;
+					match tag.as_str() {
+						"010" => lccn = Some(Lccn::from_field_010(&field)),
+						"020" => isbns.extend(Isbn::from_field_020(&field)),
+						"022" => issns.push(Issn::from_field_022(&field)),
+						"024" => {
+							other_identifiers.extend(StandardIdentifier::from_field_024(&field))
+						}
+						"028" => publisher_numbers.push(PublisherNumber::from_field_028(&field)),
+						"035" => system_control_numbers
+							.extend(SystemControlNumber::from_field_035(&field)),
+						"041" => languages.add_field_041(&field, &mut warnings),
+						"040" => cataloging_source = Some(CatalogingSource::from_field_040(&field)),
+						"043" => geographic_area_codes.extend(
+							field
+								.subfields(b'a')
+								.map(|code| code.trim_end_matches('-').to_string()),
+						),
+						"050" => lc_call_numbers.push(CallNumber::from_field_050(&field)),
+						"082" => dewey_numbers.push(DeweyNumber::from_field_082(&field)),
+						"100" => main_entry = Some(MainEntry::from_field_100(&field)),
+						"110" => main_entry = Some(MainEntry::from_field_110(&field)),
+						"111" => main_entry = Some(MainEntry::from_field_111(&field)),
+						"240" => uniform_title = Some(UniformTitle::from_field_240(&field)),
+						"245" => title = Some(TitleStatement::from_field_245(&field)),
+						"246" => varying_titles.push(VaryingTitle::from_field_246(&field)),
+						"250" => editions.push(titles::edition_from_field_250(&field)),
+						"260" => imprints.push(PublicationInfo::from_field(&field)),
+						"264" => publications.push(PublicationKind::from_field_264(&field)),
+						"300" => {
+							physical_description.push(PhysicalDescription::from_field_300(&field))
+						}
+						"336" => content_types.push(RdaTerm::from_field(&field)),
+						"337" => media_types.push(RdaTerm::from_field(&field)),
+						"338" => carrier_types.push(RdaTerm::from_field(&field)),
+						"490" => series_statements.push(SeriesStatement::from_field_490(&field)),
+						"500" => general_notes.extend(field.subfields(b'a').map(str::to_string)),
+						"504" => {
+							bibliography_notes.extend(field.subfields(b'a').map(str::to_string))
+						}
+						"505" => contents_notes.push(ContentsNote::from_field_505(&field)),
+						"520" => summaries.push(Summary::from_field_520(&field)),
+						"650" => topical_subjects.push(SubjectHeading::from_field(&field)),
+						"651" => geographic_subjects.push(SubjectHeading::from_field(&field)),
+						"700" => added_entries.push(AddedEntry::from_field_700(&field)),
+						"710" => added_entries.push(AddedEntry::from_field_710(&field)),
+						"856" => {
+							electronic_locations.push(ElectronicLocation::from_field_856(&field))
+						}
+						_ => {}
+					}
+					raw_fields.push(field);
+				}
+			}
+		}
+
+		Ok(Marc {
+			leader,
+			control_number: control_number.unwrap_or_default(),
+			control_number_identifier: control_number_identifier.unwrap_or_default(),
+			date_and_time_of_latest_record_transaction,
+			additional_material_characteristics,
+			physical_007,
+			eight,
+			lccn,
+			isbns,
+			issns,
+			other_identifiers,
+			publisher_numbers,
+			system_control_numbers,
+			cataloging_source,
+			languages,
+			geographic_area_codes,
+			lc_call_numbers,
+			dewey_numbers,
+			main_entry,
+			uniform_title,
+			title,
+			varying_titles,
+			editions,
+			imprints,
+			publications,
+			physical_description,
+			content_types,
+			media_types,
+			carrier_types,
+			series_statements,
+			general_notes,
+			bibliography_notes,
+			contents_notes,
+			summaries,
+			topical_subjects,
+			geographic_subjects,
+			added_entries,
+			electronic_locations,
+			raw_fields,
+			warnings,
+		})
+	}
+}
+
+/// `marc["245"]` is the first field with tag 245; repeated tags only return their first occurrence.
+///
+/// # Panics
+///
+/// If the record has no field with the tag. Use [`Marc::get`] if it might not.
+impl std::ops::Index<&str> for Marc {
+	type Output = Field;
+
+	fn index(&self, tag: &str) -> &Field {
+		self.get(tag)
+			.unwrap_or_else(|| panic!("record has no field with tag {tag}"))
+	}
+}
+
+/// Whether a tag set from [`Marc::tag_set`] contains `tag`. A tag that isn't three bytes long never matches.
+fn contains_tag(tags: &BTreeSet<[u8; 3]>, tag: &str) -> bool {
+	<[u8; 3]>::try_from(tag.as_bytes()).is_ok_and(|tag| tags.contains(&tag))
+}
+
+/// Normalizes a subject heading into a key for [`Marc::subject_facets`].
+fn facet_key(heading: &str) -> String {
+	heading
+		.to_lowercase()
+		.split(|c: char| !c.is_alphanumeric())
+		.filter(|word| !word.is_empty())
+		.collect::<Vec<_>>()
+		.join(" ")
+}
+
+/// Escapes the characters that would break up a line of [`Marc::to_line_mode`].
+fn escape_line_mode(value: &str) -> String {
+	value
+		.replace('\\', "\\\\")
+		.replace('\t', "\\t")
+		.replace('\n', "\\n")
+		.replace('\r', "\\r")
+}
+
+/// Skips a byte order mark at the start of a file.
+/// Only the parser entry points call this, so a byte order mark between records is still an error.
+pub(crate) fn strip_byte_order_mark(bytes: &[u8]) -> &[u8] {
+	bytes.strip_prefix(BYTE_ORDER_MARK).unwrap_or(bytes)
+}
+
+/// Reads a run of ASCII digits, as used for lengths and offsets in the leader and directory.
+pub(crate) fn parse_number(bytes: &[u8], what: &'static str) -> Result<usize, ParseError> {
+	std::str::from_utf8(bytes)
+		.ok()
+		.filter(|digits| digits.bytes().all(|b| b.is_ascii_digit()))
+		.and_then(|digits| digits.parse().ok())
+		.ok_or_else(|| ParseError::InvalidNumber {
+			what,
+			found: bytes.to_vec(),
+		})
+}
+
+/// Parses the `yyyymmddhhmmss.f` timestamp of field 005.
+/// The single digit after the point is tenths of a second.
+fn parse_date_and_time(value: &str) -> Option<NaiveDateTime> {
+	let (date_and_time, tenths) = match value.split_once('.') {
+		Some((date_and_time, tenths)) => (date_and_time, tenths),
+		None => (value, "0"),
+	};
+	if date_and_time.len() != 14 || tenths.len() != 1 {
+		return None;
+	}
+	let tenths = tenths.parse::<u32>().ok()?;
+	NaiveDateTime::parse_from_str(date_and_time, "%Y%m%d%H%M%S")
+		.ok()?
+		.with_nanosecond(tenths * 100_000_000)
+}
+
+/// Reads a blank (space) in a coded position as `#`, which is how the MARC documentation writes it.
+pub(crate) fn blank_as_hash(byte: u8) -> u8 {
+	if byte == b' ' {
+		b'#'
+	} else {
+		byte
+	}
+}
+
+/// Groups records by their control number (field 001), trimmed of the padding some systems add, e.g. LCCNs like `"   86104385 "`.
+///
+/// Records keep the order in which they were yielded within each group,
+/// so any group with more than one entry is a duplicate control number.
+/// Records whose 001 is empty (or only whitespace) are grouped under the empty string.
+pub fn group_by_control_number(records: impl Iterator<Item = Marc>) -> HashMap<String, Vec<Marc>> {
+	group_by(records, |record| record.control_number.trim().to_string())
+}
+
+/// Groups records by their control number identifier (field 003) and control number (field 001), both trimmed.
+///
+/// Control numbers are only unique within the organization assigning them,
+/// so this keeps e.g. `DLC` and `OCoLC` records with the same 001 apart when reconciling files from several sources.
+/// Records are ordered within groups as in [`group_by_control_number`], and a missing 003 or 001 keys as the empty string.
+pub fn group_by_control_number_and_identifier(
+	records: impl Iterator<Item = Marc>,
+) -> HashMap<(String, String), Vec<Marc>> {
+	group_by(records, |record| {
+		(
+			record.control_number_identifier.trim().to_string(),
+			record.control_number.trim().to_string(),
+		)
+	})
+}
+
+/// Groups records by `key`, keeping the order in which they were yielded within each group.
+fn group_by<K: Eq + std::hash::Hash>(
+	records: impl Iterator<Item = Marc>,
+	key: impl Fn(&Marc) -> K,
+) -> HashMap<K, Vec<Marc>> {
+	let mut groups: HashMap<K, Vec<Marc>> = HashMap::new();
+	for record in records {
+		groups.entry(key(&record)).or_default().push(record);
+	}
+	groups
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use additional_material_characteristics::{
+		FormOfItem, GovernmentPublication, Illustration, LiteraryForm, TargetAudience,
+	};
+	use field::Subfield;
+	use notes::SummaryKind;
+	use test_utils::record;
+
+	fn marc(control_number: &str) -> Marc {
+		Marc::from_bytes(&record(&[
+			("001", control_number),
+			("003", "DLC"),
+			("005", "19940223151047.0"),
+		]))
+		.unwrap()
+	}
+
+	#[test]
+	fn it_works() {
+		let result = 2 + 2;
+		assert_eq!(result, 4);
+	}
+
+	/// Real records kept byte for byte, rather than rebuilt by `test_utils`; see `test_data/README.md` for where they come from.
+	/// A score packaged with accompaniment CDs, in UTF-8 and catalogued under RDA, whose leader types it as a sound recording.
+	const MUSIC_SCORE: &[u8] = include_bytes!("test_data/music_score.mrc");
+	/// An e-book record in MARC-8, with 006 and 007 for the online resource.
+	const EBOOK_MARC8: &[u8] = include_bytes!("test_data/ebook_marc8.mrc");
+
+	#[test]
+	fn parses_music_score_record() {
+		let marc = Marc::from_bytes(MUSIC_SCORE).unwrap();
+		assert!(marc.warnings.is_empty());
+		assert_eq!(marc.leader.record_length, MUSIC_SCORE.len());
+		assert_eq!(marc.leader.record_status, leader::RecordStatus::Corrected);
+		assert_eq!(
+			marc.leader.type_of_record,
+			leader::TypeOfRecord::NonmusicalSoundRecording
+		);
+		assert_eq!(
+			marc.leader.bibliographic_level,
+			leader::BibliographicLevel::Monograph
+		);
+		assert_eq!(
+			marc.leader.character_coding_scheme,
+			CharacterCodingScheme::Ucs
+		);
+		assert_eq!(marc.leader.base_address_of_data, 961);
+		assert_eq!(
+			marc.leader.descriptive_cataloging_form,
+			leader::DescriptiveCatalogingForm::IsbdPunctuationIncluded
+		);
+		assert_eq!(marc.control_number, "233");
+		assert_eq!(marc.control_number_identifier, "CONS");
+		assert_eq!(
+			marc.date_and_time_of_latest_record_transaction
+				.unwrap()
+				.format("%Y-%m-%d %H:%M:%S")
+				.to_string(),
+			"2014-01-28 08:43:28"
+		);
+		assert!(marc.additional_material_characteristics.is_none());
+		let fixed = marc.eight.as_ref().unwrap();
+		assert_eq!(fixed.date_entered_on_file, "140128");
+		assert_eq!(fixed.date_1, "2013");
+		assert_eq!(fixed.place_of_publication, "nyu");
+		assert_eq!(fixed.language, "ita");
+		match &fixed.material {
+			Some(AdditionalMaterialCharacteristics::Music {
+				recorded,
+				form_of_composition,
+				..
+			}) => {
+				assert!(!recorded);
+				assert_eq!(
+					*form_of_composition,
+					additional_material_characteristics::FormOfComposition::Opera
+				);
+			}
+			other => panic!("expected music, got {:?}", other),
+		}
+		assert_eq!(marc.languages.text, ["ita", "ger", "fre", "eng"]);
+		assert_eq!(marc.main_title(), Some("The Arias for bass :"));
+		assert_eq!(marc.title.unwrap().title, "The Arias for bass");
+		assert_eq!(marc.isbns.len(), 2);
+		assert_eq!(marc.topical_subjects.len(), 2);
+	}
+
+	#[test]
+	fn parses_marc8_ebook_record() {
+		let marc = Marc::from_bytes(EBOOK_MARC8).unwrap();
+		assert!(marc.warnings.is_empty());
+		assert_eq!(marc.leader.record_length, EBOOK_MARC8.len());
+		assert_eq!(
+			marc.leader.character_coding_scheme,
+			CharacterCodingScheme::Marc8
+		);
+		assert_eq!(marc.leader.base_address_of_data, 385);
+		assert_eq!(
+			marc.leader.descriptive_cataloging_form,
+			leader::DescriptiveCatalogingForm::Aacr2
+		);
+		assert_eq!(marc.control_number, "B301882");
+		assert_eq!(marc.control_number_identifier, "");
+		assert_eq!(
+			marc.date_and_time_of_latest_record_transaction
+				.unwrap()
+				.format("%Y-%m-%d %H:%M:%S")
+				.to_string(),
+			"2020-07-28 00:00:00"
+		);
+		match marc.additional_material_characteristics {
+			Some(AdditionalMaterialCharacteristics::ComputerFile {
+				form_of_item,
+				file_type,
+				..
+			}) => {
+				assert_eq!(form_of_item, FormOfItem::Online);
+				assert_eq!(
+					file_type,
+					additional_material_characteristics::FileType::Document
+				);
+			}
+			other => panic!("expected a computer file, got {:?}", other),
+		}
+		let fixed = marc.eight.unwrap();
+		assert_eq!(fixed.place_of_publication, "quc");
+		assert_eq!(fixed.language, "fre");
+		match fixed.material {
+			Some(AdditionalMaterialCharacteristics::Book {
+				form_of_item,
+				index,
+				..
+			}) => {
+				assert_eq!(form_of_item, FormOfItem::Online);
+				assert_eq!(index, additional_material_characteristics::Index::Index);
+			}
+			other => panic!("expected a book, got {:?}", other),
+		}
+		assert_eq!(marc.physical_007.len(), 1);
+		// MARC-8 diacritics come before their letter, and decode to combining characters after it.
+		assert_eq!(
+			marc.title.unwrap().title,
+			"The\u{301}ories e\u{301}conomiques en 30 secondes"
+		);
+		assert_eq!(
+			marc.isbns
+				.iter()
+				.map(|isbn| (isbn.normalized.as_str(), isbn.canceled))
+				.collect::<Vec<_>>(),
+			[("9782896475285", true), ("9782896479764", false)]
+		);
+	}
+
+	#[test]
+	fn real_records_round_trip_through_marc_ref() {
+		for bytes in [MUSIC_SCORE, EBOOK_MARC8] {
+			let record = MarcRef::from_bytes(bytes).unwrap();
+			assert_eq!(record.as_bytes(), bytes);
+			let rebuilt: Vec<u8> = record
+				.entries()
+				.iter()
+				.flat_map(|entry| record.raw_field(entry).to_vec())
+				.collect();
+			let base_address = record.leader().base_address_of_data;
+			assert_eq!(rebuilt, bytes[base_address..bytes.len() - 1]);
+		}
+	}
+
+	#[test]
+	fn warns_about_record_length_mismatch() {
+		let mut trailing = EBOOK_MARC8.to_vec();
+		trailing.extend(b"\r\n");
+		let marc = Marc::from_bytes(&trailing).unwrap();
+		assert_eq!(marc.control_number, "B301882");
+		assert_eq!(
+			marc.warnings,
+			[
+				ParseWarning::MissingRecordTerminator,
+				ParseWarning::RecordLengthMismatch {
+					expected: EBOOK_MARC8.len(),
+					found: EBOOK_MARC8.len() + 2,
+				}
+			]
+		);
+
+		let mut stale_length = MUSIC_SCORE.to_vec();
+		stale_length[..5].copy_from_slice(b"09999");
+		let marc = Marc::from_bytes(&stale_length).unwrap();
+		assert_eq!(
+			marc.warnings,
+			[ParseWarning::RecordLengthMismatch {
+				expected: 9999,
+				found: MUSIC_SCORE.len(),
+			}]
+		);
+	}
+
+	#[test]
+	fn parses_book_record() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "   86104385 "),
+			("003", "DLC"),
+			("005", "19940223151047.0"),
+			("006", "abf  g      001 1 "),
+			("008", "830222s1983    nyua          000 1 eng  "),
+			("245", "10$aThe great gatsby /$cF. Scott Fitzgerald."),
+		]))
+		.unwrap();
+		assert_eq!(
+			marc.leader.type_of_record,
+			leader::TypeOfRecord::LanguageMaterial
+		);
+		assert_eq!(marc.control_number, "   86104385 ");
+		assert_eq!(marc.control_number_identifier, "DLC");
+		match marc.additional_material_characteristics {
+			Some(AdditionalMaterialCharacteristics::Book {
+				manuscript,
+				illustrations,
+				target_audience,
+				government_publication,
+				literary_form,
+				..
+			}) => {
+				assert!(!manuscript);
+				assert_eq!(illustrations[0], Illustration::Maps);
+				assert_eq!(illustrations[1], Illustration::Plates);
+				assert_eq!(target_audience, TargetAudience::General);
+				assert_eq!(government_publication, GovernmentPublication::None);
+				assert_eq!(literary_form, LiteraryForm::Fiction);
+			}
+			other => panic!("expected a book, got {:?}", other),
+		}
+		let title = marc.title.unwrap();
+		assert_eq!(title.title, "The great gatsby");
+		assert_eq!(
+			title.statement_of_responsibility.as_deref(),
+			Some("F. Scott Fitzgerald.")
+		);
+		let fixed = marc.eight.unwrap();
+		assert_eq!(fixed.language, "eng");
+		assert!(matches!(
+			fixed.material,
+			Some(AdditionalMaterialCharacteristics::Book { .. })
+		));
+	}
+
+	#[test]
+	fn reads_original_language() {
+		let marc = |fields: &[(&str, &str)]| {
+			let mut all = vec![
+				("001", "1"),
+				("008", "830222s1983    nyua          000 1 eng  "),
+			];
+			all.extend_from_slice(fields);
+			Marc::from_bytes(&record(&all)).unwrap()
+		};
+		let translation = marc(&[("041", "1 $aeng$hrus$hger")]);
+		assert_eq!(translation.original_language(), Some(vec!["rus", "ger"]));
+		let marked_only = marc(&[("041", "1 $aeng")]);
+		assert_eq!(marked_only.original_language(), Some(vec!["eng"]));
+		let original = marc(&[("041", "0 $aeng$afre")]);
+		assert_eq!(original.original_language(), None);
+		assert_eq!(marc(&[]).original_language(), None);
+	}
+
+	#[test]
+	fn deduplicates_subject_facets() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("650", " 0$aPhilosophy$xHistory."),
+			("650", " 7$aPHILOSOPHY$xHistory$2fast"),
+			("650", " 0$aSpace colonies"),
+			("651", " 0$aMexico$xHistory$yRevolution, 1910-1920."),
+		]))
+		.unwrap();
+		assert_eq!(
+			marc.subject_facets(),
+			[
+				(
+					"Philosophy -- History.".to_string(),
+					"philosophy history".to_string()
+				),
+				("Space colonies".to_string(), "space colonies".to_string()),
+				(
+					"Mexico -- History -- Revolution, 1910-1920.".to_string(),
+					"mexico history revolution 1910 1920".to_string()
+				),
+			]
+		);
+	}
+
+	#[test]
+	fn main_title_is_verbatim() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("245", "14$aThe great gatsby /$cF. Scott Fitzgerald."),
+		]))
+		.unwrap();
+		assert_eq!(marc.main_title(), Some("The great gatsby /"));
+		assert_eq!(marc.title.unwrap().title, "The great gatsby");
+		let untitled = Marc::from_bytes(&record(&[("001", "2")])).unwrap();
+		assert_eq!(untitled.main_title(), None);
+	}
+
+	#[test]
+	fn reads_part_info_from_245() {
+		let part = Marc::from_bytes(&record(&[
+			("001", "1"),
+			(
+				"245",
+				"10$aLord of the rings.$nPart 2,$pThe two towers /$cJ.R.R. Tolkien.",
+			),
+		]))
+		.unwrap();
+		let part_info = part.part_info().unwrap();
+		assert_eq!(part_info.numbers().collect::<Vec<_>>(), ["Part 2"]);
+		assert_eq!(part_info.names().collect::<Vec<_>>(), ["The two towers"]);
+		let whole = Marc::from_bytes(&record(&[
+			("001", "2"),
+			("245", "10$aLord of the rings /$cJ.R.R. Tolkien."),
+		]))
+		.unwrap();
+		assert!(whole.part_info().is_none());
+		assert!(Marc::from_bytes(&record(&[("001", "3")]))
+			.unwrap()
+			.part_info()
+			.is_none());
+	}
+
+	#[test]
+	fn parses_record_without_006() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "ocm14919759"),
+			("003", "OCoLC"),
+			("005", "20020314120000.0"),
+		]))
+		.unwrap();
+		assert_eq!(marc.control_number, "ocm14919759");
+		assert_eq!(marc.control_number_identifier, "OCoLC");
+		assert!(marc.additional_material_characteristics.is_none());
+	}
+
+	#[test]
+	fn reads_control_number_verbatim() {
+		// The documentation's `#880524405##`, with blanks as they appear in a record.
+		let marc = marc(" 880524405  ");
+		assert_eq!(marc.control_number, " 880524405  ");
+	}
+
+	#[test]
+	fn missing_control_number_is_empty() {
+		let marc =
+			Marc::from_bytes(&record(&[("003", "CaOONL"), ("005", "19940223151047.0")])).unwrap();
+		assert_eq!(marc.control_number, "");
+	}
+
+	#[test]
+	fn reads_control_number_identifier() {
+		for identifier in ["DLC", "CaOONL", "OCoLC"] {
+			let marc = Marc::from_bytes(&record(&[
+				("001", "1"),
+				("003", identifier),
+				("005", "19940223151047.0"),
+			]))
+			.unwrap();
+			assert_eq!(marc.control_number_identifier, identifier);
+		}
+	}
+
+	#[test]
+	fn missing_control_number_identifier_is_empty() {
+		let marc = Marc::from_bytes(&record(&[("001", "1"), ("005", "19940223151047.0")])).unwrap();
+		assert_eq!(marc.control_number_identifier, "");
+	}
+
+	#[test]
+	fn parses_date_and_time_of_latest_record_transaction() {
+		let marc = marc("1");
+		assert_eq!(
+			marc.date_and_time_of_latest_record_transaction
+				.unwrap()
+				.format("%Y-%m-%d %H:%M:%S%.3f")
+				.to_string(),
+			"1994-02-23 15:10:47.000"
+		);
+		assert_eq!(
+			parse_date_and_time("19940223151047.5")
+				.unwrap()
+				.format("%H:%M:%S%.3f")
+				.to_string(),
+			"15:10:47.500"
+		);
+		assert!(parse_date_and_time("19940223151047").is_some());
+		assert!(parse_date_and_time("1994022315104.0").is_none());
+		assert!(parse_date_and_time("19941323151047.0").is_none());
+		assert!(parse_date_and_time("19940223151047.05").is_none());
+	}
+
+	#[test]
+	fn missing_or_malformed_005_is_none() {
+		let missing = Marc::from_bytes(&record(&[("001", "1"), ("003", "DLC")])).unwrap();
+		assert!(missing.date_and_time_of_latest_record_transaction.is_none());
+		let malformed =
+			Marc::from_bytes(&record(&[("001", "1"), ("005", "00000000000000.0")])).unwrap();
+		assert!(malformed
+			.date_and_time_of_latest_record_transaction
+			.is_none());
+	}
+
+	#[test]
+	fn decodes_marc8_control_fields() {
+		let marc = Marc::from_bytes(&test_utils::record_with_leader(
+			b"00000nam  2200000   4500",
+			&[("001", b"K\xB2benhavn \xE2e")],
+		))
+		.unwrap();
+		assert_eq!(marc.control_number, "K\u{f8}benhavn e\u{301}");
+	}
+
+	#[test]
+	fn picks_decoder_from_character_coding_scheme() {
+		// `ø` in UTF-8, which MARC-8 reads as `©` and dotless `ı`.
+		let control_number = |leader: &[u8; 24]| {
+			Marc::from_bytes(&test_utils::record_with_leader(
+				leader,
+				&[("001", b"K\xC3\xB8benhavn")],
+			))
+			.unwrap()
+			.control_number
+		};
+		assert_eq!(
+			control_number(b"00000nam a2200000   4500"),
+			"K\u{f8}benhavn"
+		);
+		assert_eq!(
+			control_number(b"00000nam  2200000   4500"),
+			"K\u{a9}\u{131}benhavn"
+		);
+	}
+
+	#[test]
+	fn warns_about_unknown_character_coding_scheme() {
+		let marc = Marc::from_bytes(&test_utils::record_with_leader(
+			b"00000nam z2200000   4500",
+			&[("001", "K\u{f8}benhavn".as_bytes())],
+		))
+		.unwrap();
+		assert_eq!(marc.control_number, "K\u{f8}benhavn");
+		assert_eq!(
+			marc.warnings,
+			[ParseWarning::UnknownCharacterCodingScheme { found: b'z' }]
+		);
+	}
+
+	#[test]
+	fn lossy_parsing_replaces_bad_bytes() {
+		let bytes = test_utils::record_with_leader(
+			b"00000nxm a2200000   4500",
+			&[("001", b"ocm\xFF1234"), ("003", b"DLC")],
+		);
+		assert!(Marc::from_bytes(&bytes).is_err());
+		let marc = Marc::from_bytes_lossy(&bytes).unwrap();
+		assert_eq!(marc.control_number, "ocm\u{FFFD}1234");
+		assert_eq!(marc.control_number_identifier, "DLC");
+		assert_eq!(marc.leader.type_of_record, leader::TypeOfRecord::NotCoded);
+	}
+
+	#[test]
+	fn strict_parsing_rejects_invalid_utf8() {
+		let bytes =
+			test_utils::record_with_leader(b"00000nam a2200000   4500", &[("001", b"ocm\xFF1234")]);
+		assert!(matches!(
+			Marc::from_bytes(&bytes),
+			Err(ParseError::InvalidUtf8 { tag }) if tag == "001"
+		));
+	}
+
+	#[test]
+	fn skips_byte_order_mark() {
+		let bytes = record(&[("001", "1"), ("003", "DLC")]);
+		let with_bom = [BYTE_ORDER_MARK, &bytes].concat();
+		assert_eq!(
+			format!("{:?}", Marc::from_bytes(&with_bom).unwrap()),
+			format!("{:?}", Marc::from_bytes(&bytes).unwrap())
+		);
+	}
+
+	#[test]
+	fn reads_lccn() {
+		let marc = Marc::from_bytes(&record(&[("001", "1"), ("010", "  $a   86104385 ")])).unwrap();
+		let lccn = marc.lccn.unwrap();
+		assert_eq!(lccn.number, "86104385");
+		assert_eq!(lccn.raw, "   86104385 ");
+	}
+
+	#[test]
+	fn collects_isbns_from_every_020() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("020", "  $a0684801523$qhardcover$z0684801529"),
+			("020", "  $a9780743273565 (pbk.)"),
+		]))
+		.unwrap();
+		let isbns: Vec<(&str, bool)> = marc
+			.isbns
+			.iter()
+			.map(|isbn| (isbn.normalized.as_str(), isbn.canceled))
+			.collect();
+		assert_eq!(
+			isbns,
+			[
+				("0684801523", false),
+				("0684801529", true),
+				("9780743273565", false)
+			]
+		);
+	}
+
+	#[test]
+	fn collects_issns_in_order() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("022", "0 $a0046-225X$l0046-225X"),
+			("022", "1 $a1234-5679"),
+		]))
+		.unwrap();
+		let issns: Vec<&str> = marc.issns.iter().map(|issn| issn.value.as_str()).collect();
+		assert_eq!(issns, ["0046-225X", "1234-5679"]);
+	}
+
+	#[test]
+	fn reads_main_entry() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			(
+				"100",
+				"1 $aFitzgerald, F. Scott$q(Francis Scott),$d1896-1940.",
+			),
+		]))
+		.unwrap();
+		match marc.main_entry {
+			Some(names::MainEntry::PersonalName(name)) => {
+				assert_eq!(name.name, "Fitzgerald, F. Scott");
+				assert_eq!(name.dates.as_deref(), Some("1896-1940."));
+			}
+			other => panic!("expected a personal name, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn collects_every_edition() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("250", "  $a2nd ed."),
+			("250", "  $aLarge print ed."),
+		]))
+		.unwrap();
+		assert_eq!(marc.editions, ["2nd ed.", "Large print ed."]);
+	}
+
+	#[test]
+	fn reads_publication() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("260", "  $aNew York :$bC. Scribner's Sons,$c1925."),
+		]))
+		.unwrap();
+		let [publication] = marc.imprints.as_slice() else {
+			panic!("expected one imprint");
+		};
+		assert_eq!(publication.place, ["New York"]);
+		assert_eq!(publication.publisher, ["C. Scribner's Sons"]);
+		assert_eq!(publication.date, ["1925."]);
+	}
+
+	#[test]
+	fn reads_rda_publication_statements_in_order() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("264", " 1$aNew York :$bScribner,$c2018."),
+			("264", " 4$c\u{a9}2018"),
+		]))
+		.unwrap();
+		let kinds: Vec<_> = marc.publications.iter().map(|(kind, _)| *kind).collect();
+		assert_eq!(
+			kinds,
+			[
+				PublicationKind::Publication,
+				PublicationKind::CopyrightNotice
+			]
+		);
+		assert_eq!(marc.publications[0].1.publisher, ["Scribner"]);
+		assert_eq!(marc.publications[1].1.date, ["\u{a9}2018"]);
+		assert!(marc.imprints.is_empty());
+	}
+
+	#[test]
+	fn reads_physical_description() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("300", "  $a324 pages :$billustrations ;$c24 cm"),
+		]))
+		.unwrap();
+		let [description] = marc.physical_description.as_slice() else {
+			panic!("expected one physical description");
+		};
+		assert_eq!(description.extent, ["324 pages"]);
+		assert_eq!(description.other_details.as_deref(), Some("illustrations"));
+		assert_eq!(description.dimensions.as_deref(), Some("24 cm"));
+	}
+
+	#[test]
+	fn reads_content_media_and_carrier_types() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("336", "  $atext$btxt$2rdacontent"),
+			("336", "  $astill image$bsti$2rdacontent"),
+			("337", "  $acomputer$bc$2rdamedia"),
+			("338", "  $aonline resource$bcr$2rdacarrier"),
+		]))
+		.unwrap();
+		let terms = |types: &[RdaTerm]| -> Vec<String> {
+			types.iter().map(|rda| rda.term.clone()).collect()
+		};
+		assert_eq!(terms(&marc.content_types), ["text", "still image"]);
+		assert_eq!(terms(&marc.media_types), ["computer"]);
+		assert_eq!(marc.carrier_types[0].code.as_deref(), Some("cr"));
+	}
+
+	#[test]
+	fn collects_series_statements() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("490", "0 $aPenguin classics"),
+			("490", "1 $aOxford world's classics"),
+		]))
+		.unwrap();
+		let [penguin, oxford] = marc.series_statements.as_slice() else {
+			panic!("expected two series statements");
+		};
+		assert_eq!(penguin.title, "Penguin classics");
+		assert!(!penguin.traced);
+		assert_eq!(penguin.volume, None);
+		assert_eq!(oxford.title, "Oxford world's classics");
+		assert!(oxford.traced);
+	}
+
+	#[test]
+	fn collects_general_notes_in_order() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("500", "  $aTranslation of: L'\u{e9}tranger."),
+			("500", "  $aIncludes index."),
+			("500", "  $a\"A Borzoi book.\""),
+		]))
+		.unwrap();
+		assert_eq!(
+			marc.general_notes,
+			[
+				"Translation of: L'\u{e9}tranger.",
+				"Includes index.",
+				"\"A Borzoi book.\""
+			]
+		);
+	}
+
+	#[test]
+	fn has_bibliography_from_504() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			(
+				"504",
+				"  $aIncludes bibliographical references (pages 200-210).",
+			),
+		]))
+		.unwrap();
+		assert_eq!(
+			marc.bibliography_notes,
+			["Includes bibliographical references (pages 200-210)."]
+		);
+		assert!(marc.has_bibliography());
+		assert!(!Marc::from_bytes(&record(&[("001", "1")]))
+			.unwrap()
+			.has_bibliography());
+	}
+
+	#[test]
+	fn has_bibliography_from_nature_of_contents() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("008", "850101s1925    nyu      b   0001 0 eng d"),
+		]))
+		.unwrap();
+		assert!(marc.bibliography_notes.is_empty());
+		assert!(marc.has_bibliography());
+	}
+
+	#[test]
+	fn reads_enhanced_contents_note() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			(
+				"505",
+				"00$tThe fellowship of the ring --$tThe two towers --$tThe return of the king.",
+			),
+		]))
+		.unwrap();
+		assert_eq!(
+			marc.contents_notes[0].titles,
+			[
+				"The fellowship of the ring",
+				"The two towers",
+				"The return of the king."
+			]
+		);
+	}
+
+	#[test]
+	fn collects_summaries() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("520", "  $aA boy befriends a dragon."),
+			("520", "4 $aContains mild peril."),
+		]))
+		.unwrap();
+		let kinds: Vec<_> = marc.summaries.iter().map(|summary| summary.kind).collect();
+		assert_eq!(kinds, [SummaryKind::Summary, SummaryKind::ContentAdvice]);
+		assert_eq!(marc.summaries[1].text, "Contains mild peril.");
+	}
+
+	#[test]
+	fn collects_topical_subjects() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("650", " 0$aDragons$vJuvenile fiction."),
+			("650", " 7$aFriendship.$2fast"),
+		]))
+		.unwrap();
+		let topics: Vec<_> = marc
+			.topical_subjects
+			.iter()
+			.map(|subject| subject.topic.as_str())
+			.collect();
+		assert_eq!(topics, ["Dragons", "Friendship."]);
+		assert_eq!(
+			marc.topical_subjects[0].subdivisions,
+			[subjects::Subdivision::Form("Juvenile fiction.".to_string())]
+		);
+	}
+
+	#[test]
+	fn reads_geographic_subject() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("651", " 0$aFrance$xHistory$y1789-1799"),
+		]))
+		.unwrap();
+		let [france] = marc.geographic_subjects.as_slice() else {
+			panic!("expected one geographic subject");
+		};
+		assert_eq!(france.topic, "France");
+		assert_eq!(
+			france.subdivisions,
+			[
+				subjects::Subdivision::General("History".to_string()),
+				subjects::Subdivision::Chronological("1789-1799".to_string()),
+			]
+		);
+		assert_eq!(france.thesaurus, subjects::SubjectThesaurus::Lcsh);
+		assert!(marc.topical_subjects.is_empty());
+	}
+
+	#[test]
+	fn collects_added_personal_names() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("100", "1 $aGaiman, Neil,$eauthor."),
+			("700", "1 $aPratchett, Terry,$eauthor.$4aut"),
+			("700", "1 $aKidby, Paul,$eillustrator."),
+		]))
+		.unwrap();
+		let names: Vec<_> = marc
+			.added_entries
+			.iter()
+			.map(|entry| match entry {
+				AddedEntry::PersonalName { name, title, kind } => {
+					assert_eq!(*title, None);
+					assert_eq!(*kind, names::TypeOfAddedEntry::NoInformation);
+					name.name.as_str()
+				}
+				AddedEntry::CorporateName { .. } => panic!("expected only personal names"),
+			})
+			.collect();
+		assert_eq!(names, ["Pratchett, Terry", "Kidby, Paul"]);
+	}
+
+	#[test]
+	fn keeps_personal_and_corporate_added_entries_in_order() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("710", "2 $aBBC Books."),
+			("700", "1 $aPratchett, Terry."),
+			("710", "2 $aRandom House."),
+		]))
+		.unwrap();
+		let names: Vec<_> = marc
+			.added_entries
+			.iter()
+			.map(|entry| match entry {
+				AddedEntry::PersonalName { name, .. } => name.name.as_str(),
+				AddedEntry::CorporateName { name, .. } => name.name.as_str(),
+			})
+			.collect();
+		assert_eq!(names, ["BBC Books.", "Pratchett, Terry.", "Random House."]);
+	}
+
+	#[test]
+	fn collects_electronic_locations() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("856", "40$uhttps://example.com/book.pdf"),
+			("856", "42$3Cover image$uhttps://example.com/cover.jpg"),
+		]))
+		.unwrap();
+		let uris: Vec<_> = marc
+			.electronic_locations
+			.iter()
+			.map(|location| location.uri.as_str())
+			.collect();
+		assert_eq!(
+			uris,
+			[
+				"https://example.com/book.pdf",
+				"https://example.com/cover.jpg"
+			]
+		);
+		assert_eq!(
+			marc.electronic_locations[0].access_method,
+			locations::AccessMethod::Http
+		);
+	}
+
+	#[test]
+	fn reads_languages() {
+		let marc = Marc::from_bytes(&record(&[("001", "1"), ("041", "1 $aeng$hjpn")])).unwrap();
+		assert_eq!(marc.languages.text, ["eng"]);
+		assert_eq!(marc.languages.original, ["jpn"]);
+		assert!(marc.languages.translation);
+		let marc = Marc::from_bytes(&record(&[("001", "1")])).unwrap();
+		assert_eq!(marc.languages, LanguageInfo::default());
+	}
+
+	#[test]
+	fn trims_geographic_area_codes() {
+		let marc =
+			Marc::from_bytes(&record(&[("001", "1"), ("043", "  $an-us-ny$ae-fr---")])).unwrap();
+		assert_eq!(marc.geographic_area_codes, ["n-us-ny", "e-fr"]);
+	}
+
+	#[test]
+	fn collects_lc_call_numbers() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("050", "00$aPS3511.I9$bG7 1925"),
+			("050", " 4$aPS3511.I9"),
+		]))
+		.unwrap();
+		let [lc, other] = marc.lc_call_numbers.as_slice() else {
+			panic!("expected two call numbers");
+		};
+		assert_eq!(lc.classification, "PS3511.I9");
+		assert_eq!(lc.item.as_deref(), Some("G7 1925"));
+		assert_eq!(other.source, classification::CallNumberSource::OtherAgency);
+	}
+
+	#[test]
+	fn collects_dewey_numbers() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("082", "00$a813/.52$222"),
+			("082", "14$a813$215"),
+		]))
+		.unwrap();
+		let numbers: Vec<_> = marc
+			.dewey_numbers
+			.iter()
+			.map(|dewey| (dewey.number.as_str(), dewey.edition.as_deref()))
+			.collect();
+		assert_eq!(numbers, [("813/.52", Some("22")), ("813", Some("15"))]);
+	}
+
+	#[test]
+	fn collects_system_control_numbers() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("035", "  $a(OCoLC)14919759"),
+			("035", "  $a(DLC)   86104385"),
+		]))
+		.unwrap();
+		let numbers: Vec<_> = marc
+			.system_control_numbers
+			.iter()
+			.map(|number| (number.prefix.as_deref(), number.number.as_str()))
+			.collect();
+		assert_eq!(
+			numbers,
+			[(Some("OCoLC"), "14919759"), (Some("DLC"), "86104385")]
+		);
+	}
+
+	#[test]
+	fn keeps_every_field_in_raw_fields() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "ocm123"),
+			("245", "10$aDune /$cFrank Herbert."),
+			("949", "  $aLocal call number$bshelf 3"),
+		]))
+		.unwrap();
+		let tags: Vec<_> = marc
+			.raw_fields
+			.iter()
+			.map(|field| field.tag.as_str())
+			.collect();
+		assert_eq!(tags, ["001", "245", "949"]);
+		assert_eq!(
+			marc.raw_fields[0],
+			Field {
+				tag: "001".to_string(),
+				indicators: *b"  ",
+				subfields: vec![],
+				control_value: Some("ocm123".to_string()),
+			}
+		);
+		let local = &marc.raw_fields[2];
+		assert_eq!(local.subfield(b'a'), Some("Local call number"));
+		assert_eq!(local.subfield(b'b'), Some("shelf 3"));
+	}
+
+	#[test]
+	fn looks_up_subfields() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("245", "10$aDune /$cFrank Herbert."),
+			("650", " 0$aDesert ecology$vFiction."),
+			("650", " 0$aSpace colonies$vFiction."),
+		]))
+		.unwrap();
+		assert_eq!(marc.get_subfield("245", b'a'), Some("Dune /"));
+		assert_eq!(marc.get_subfield("650", b'a'), Some("Desert ecology"));
+		assert_eq!(
+			marc.get_subfields("650", b'a'),
+			["Desert ecology", "Space colonies"]
+		);
+	}
+
+	#[test]
+	fn looks_up_missing_subfields() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("245", "10$aDune /$cFrank Herbert."),
+		]))
+		.unwrap();
+		assert_eq!(marc.get_subfield("245", b'b'), None);
+		assert_eq!(marc.get_subfield("100", b'a'), None);
+		assert!(marc.get_subfields("650", b'a').is_empty());
+	}
+
+	#[test]
+	fn reads_field_indicators() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("245", "14$aThe great gatsby"),
+			("650", " 0$aRich people$vFiction."),
+			("650", " 7$aWealth.$2fast"),
+		]))
+		.unwrap();
+		assert_eq!(marc.field_indicators("650"), Some(*b" 0"));
+		assert_eq!(marc.field_indicators("245"), Some(*b"14"));
+		assert_eq!(marc.field_indicators("100"), None);
+		assert_eq!(marc.raw_fields[3].indicators(), *b" 7");
+	}
+
+	#[test]
+	fn keeps_every_repeated_field() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("260", "  $aLondon :$bMethuen,$c1925."),
+			("260", "3 $aNew York :$bDoubleday,$c1926."),
+			("505", "0 $av. 1. Spring -- v. 2. Summer."),
+			("505", "8 $av. 3. Autumn -- v. 4. Winter."),
+			("650", " 0$aSeasons."),
+			("650", " 0$aSpring."),
+			("650", " 0$aSummer."),
+			("650", " 0$aAutumn."),
+			("650", " 0$aWinter."),
+		]))
+		.unwrap();
+		assert_eq!(marc.count_fields("650"), 5);
+		assert_eq!(marc.topical_subjects.len(), 5);
+		assert_eq!(marc.topical_subjects[4].topic, "Winter.");
+		assert_eq!(marc.count_fields("260"), 2);
+		assert_eq!(marc.imprints[1].publisher, ["Doubleday"]);
+		assert_eq!(marc.count_fields("505"), 2);
+		assert_eq!(marc.contents_notes.len(), 2);
+		assert_eq!(marc.count_fields("001"), 1);
+		assert_eq!(marc.count_fields("700"), 0);
+	}
+
+	#[test]
+	fn indexes_fields_by_tag() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("245", "10$aDune /$cFrank Herbert."),
+			("650", " 0$aDesert ecology"),
+			("650", " 0$aSpace colonies"),
+		]))
+		.unwrap();
+		assert_eq!(marc["245"].subfield(b'c'), Some("Frank Herbert."));
+		assert_eq!(marc["650"].subfield(b'a'), Some("Desert ecology"));
+		assert_eq!(marc.get("245"), Some(&marc["245"]));
+		assert_eq!(marc.get("100"), None);
+	}
+
+	#[test]
+	fn checks_tags_against_tag_set() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("100", "1 $aHerbert, Frank."),
+			("245", "10$aDune /$cFrank Herbert."),
+			("650", " 0$aDesert ecology"),
+			("650", " 0$aSpace colonies"),
+		]))
+		.unwrap();
+		assert_eq!(
+			marc.tag_set(),
+			BTreeSet::from([*b"001", *b"100", *b"245", *b"650"])
+		);
+		assert!(marc.has_all(&["245", "100"]));
+		assert!(!marc.has_all(&["245", "100", "856"]));
+		assert!(marc.has_any(&["856", "650"]));
+		assert!(!marc.has_any(&["856", "24"]));
+	}
+
+	/// Reads back the output of [`Marc::to_line_mode`].
+	fn from_line_mode(lines: &str) -> Vec<Field> {
+		fn unescape(value: &str) -> String {
+			let mut unescaped = String::new();
+			let mut chars = value.chars();
+			while let Some(c) = chars.next() {
+				unescaped.push(if c == '\\' {
+					match chars.next().unwrap() {
+						't' => '\t',
+						'n' => '\n',
+						'r' => '\r',
+						other => other,
+					}
+				} else {
+					c
+				});
+			}
+			unescaped
+		}
+		let mut fields: Vec<Field> = Vec::new();
+		let mut current = None;
+		for line in lines.lines() {
+			let columns: Vec<String> = line.split('\t').map(unescape).collect();
+			let key = (columns[0].clone(), columns[1].clone());
+			if current.as_ref() != Some(&key) {
+				fields.push(if columns[0].starts_with("00") {
+					Field::control(&columns[0], columns[2].clone())
+				} else {
+					Field {
+						tag: columns[0].clone(),
+						indicators: columns[2].as_bytes().try_into().unwrap(),
+						subfields: Vec::new(),
+						control_value: None,
+					}
+				});
+				current = Some(key);
+			}
+			if let [_, _, _, code, value] = columns.as_slice() {
+				fields.last_mut().unwrap().subfields.push(Subfield {
+					code: code.as_bytes()[0],
+					value: value.clone(),
+				});
+			}
+		}
+		fields
+	}
+
+	#[test]
+	fn line_mode_round_trips() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "   86104385 "),
+			("245", "10$aThe great gatsby /$cF. Scott Fitzgerald."),
+			("500", "  $aTabs\there,\nnewlines and a back\\slash."),
+			("650", " 0$aRich people"),
+			("650", " 0$aLong Island (N.Y.)"),
+			("999", "  "),
+		]))
+		.unwrap();
+		let lines = marc.to_line_mode();
+		assert_eq!(
+			lines,
+			"001\t1\t   86104385 \n\
+			 245\t1\t10\ta\tThe great gatsby /\n\
+			 245\t1\t10\tc\tF. Scott Fitzgerald.\n\
+			 500\t1\t  \ta\tTabs\\there,\\nnewlines and a back\\\\slash.\n\
+			 650\t1\t 0\ta\tRich people\n\
+			 650\t2\t 0\ta\tLong Island (N.Y.)\n\
+			 999\t1\t  \n"
+		);
+		assert_eq!(from_line_mode(&lines), marc.raw_fields);
+	}
+
+	#[test]
+	#[should_panic(expected = "record has no field with tag 100")]
+	fn indexing_missing_tag_panics() {
+		let marc = Marc::from_bytes(&record(&[("001", "1")])).unwrap();
+		let _ = &marc["100"];
+	}
+
+	#[test]
+	fn tells_control_fields_from_data_fields() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "ocm123"),
+			("245", "10$aDune /$cFrank Herbert."),
+		]))
+		.unwrap();
+		let control = &marc["001"];
+		assert!(control.is_control());
+		assert_eq!(control.control_value.as_deref(), Some("ocm123"));
+		assert!(control.subfields.is_empty());
+		let data = &marc["245"];
+		assert!(!data.is_control());
+		assert_eq!(data.control_value, None);
+		assert_eq!(data.subfields.len(), 2);
+	}
+
+	#[test]
+	fn collects_other_identifiers() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("024", "1 $a093624526722"),
+			("024", "2 $aM570406203"),
+		]))
+		.unwrap();
+		let kinds: Vec<_> = marc
+			.other_identifiers
+			.iter()
+			.map(|identifier| identifier.kind)
+			.collect();
+		assert_eq!(
+			kinds,
+			[
+				identifiers::IdentifierKind::Upc,
+				identifiers::IdentifierKind::Ismn
+			]
+		);
+		assert_eq!(marc.other_identifiers[0].value, "093624526722");
+	}
+
+	#[test]
+	fn collects_publisher_numbers() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("028", "02$a289 477 054-2$bDeutsche Grammophon"),
+			("028", "22$aB. & H. 8797$bBreitkopf & H\u{e4}rtel"),
+		]))
+		.unwrap();
+		let [issue, plate] = marc.publisher_numbers.as_slice() else {
+			panic!("expected two publisher numbers");
+		};
+		assert_eq!(issue.kind, identifiers::PublisherNumberKind::IssueNumber);
+		assert_eq!(issue.source.as_deref(), Some("Deutsche Grammophon"));
+		assert_eq!(plate.kind, identifiers::PublisherNumberKind::PlateNumber);
+		assert_eq!(plate.number, "B. & H. 8797");
+	}
+
+	#[test]
+	fn collects_physical_007() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("007", "sd fsngnnmmned"),
+			("007", "vd cvaizq"),
+			("007", "cr cn 024auaua"),
+		]))
+		.unwrap();
+		let [sound, electronic] = marc.physical_007.as_slice() else {
+			panic!("expected two decoded 007s");
+		};
+		assert!(matches!(
+			sound,
+			PhysicalDescription007::SoundRecording { .. }
+		));
+		assert!(matches!(
+			electronic,
+			PhysicalDescription007::Electronic { .. }
+		));
+		assert_eq!(marc.count_fields("007"), 3);
+	}
+
+	#[test]
+	fn reads_cataloging_source() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("040", "  $aDLC$beng$erda$cDLC$dOCoLC"),
+		]))
+		.unwrap();
+		let source = marc.cataloging_source.unwrap();
+		assert_eq!(source.description_conventions.as_deref(), Some("rda"));
+		assert_eq!(source.original_agency.as_deref(), Some("DLC"));
+		assert_eq!(source.modifying_agencies, ["OCoLC"]);
+		let marc = Marc::from_bytes(&record(&[("001", "1")])).unwrap();
+		assert_eq!(marc.cataloging_source, None);
+	}
+
+	#[test]
+	fn reads_uniform_title() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("100", "1 $aShakespeare, William,$d1564-1616."),
+			("240", "10$aHamlet.$lFrench"),
+			("245", "10$aHamlet /$cShakespeare"),
+		]))
+		.unwrap();
+		let uniform_title = marc.uniform_title.unwrap();
+		assert_eq!(uniform_title.title, "Hamlet.");
+		assert_eq!(uniform_title.language.as_deref(), Some("French"));
+	}
+
+	#[test]
+	fn collects_varying_titles() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("245", "14$aThe great gatsby"),
+			("246", "30$aGreat gatsby"),
+			("246", "14$aGatsby"),
+		]))
+		.unwrap();
+		let titles: Vec<_> = marc
+			.varying_titles
+			.iter()
+			.map(|title| (title.kind, title.title.as_str()))
+			.collect();
+		assert_eq!(
+			titles,
+			[
+				(titles::VaryingTitleKind::Portion, "Great gatsby"),
+				(titles::VaryingTitleKind::Cover, "Gatsby"),
+			]
+		);
+	}
+
+	#[test]
+	fn tolerates_missing_record_terminator() {
+		let mut bytes = record(&[("001", "1"), ("003", "DLC")]);
+		assert_eq!(bytes.pop(), Some(RECORD_TERMINATOR));
+		let marc = Marc::from_bytes(&bytes).unwrap();
+		assert_eq!(marc.control_number, "1");
+		assert_eq!(marc.control_number_identifier, "DLC");
+		assert_eq!(
+			marc.warnings,
+			vec![
+				ParseWarning::MissingRecordTerminator,
+				ParseWarning::RecordLengthMismatch {
+					expected: bytes.len() + 1,
+					found: bytes.len(),
+				}
+			]
+		);
+	}
+
+	#[test]
+	fn warns_about_missing_field_terminator() {
+		let mut bytes = record(&[("001", "1"), ("003", "DLC")]);
+		// Overwrite 001's terminator; 003 must still be read from its own offset.
+		let base_address = parse_number(&bytes[12..17], "base address").unwrap();
+		bytes[base_address + 1] = b'2';
+		let marc = Marc::from_bytes(&bytes).unwrap();
+		assert_eq!(marc.control_number, "12");
+		assert_eq!(marc.control_number_identifier, "DLC");
+		assert_eq!(
+			marc.warnings,
+			vec![ParseWarning::MissingFieldTerminator {
+				tag: "001".to_string()
+			}]
+		);
+	}
+
+	#[test]
+	fn well_formed_record_has_no_warnings() {
+		assert!(marc("1").warnings.is_empty());
+	}
+
+	#[test]
+	fn rejects_short_leader() {
+		assert!(matches!(
+			Marc::from_bytes(b"00024nam"),
+			Err(ParseError::LeaderTooShort(8))
+		));
+	}
+
+	#[test]
+	fn rejects_base_address_past_end() {
+		let mut bytes = record(&[("001", "1"), ("003", "DLC"), ("005", "19940223151047.0")]);
+		bytes[12..17].copy_from_slice(b"99999");
+		assert!(matches!(
+			Marc::from_bytes(&bytes),
+			Err(ParseError::BaseAddressOutOfBounds {
+				base_address: 99999,
+				..
+			})
+		));
+	}
+
+	#[test]
+	fn parses_mixed_materials_006() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("003", "DLC"),
+			("005", "19940223151047.0"),
+			("006", "p     o           "),
+		]))
+		.unwrap();
+		assert!(matches!(
+			marc.additional_material_characteristics,
+			Some(AdditionalMaterialCharacteristics::MixedMaterials {
+				form_of_item: FormOfItem::Online
+			})
+		));
+	}
+
+	#[test]
+	fn groups_duplicate_control_numbers() {
+		let records = vec![
+			marc("1"),
+			marc("2"),
+			marc("1"),
+			marc(""),
+			marc("  "),
+			marc(" 2 "),
+		];
+		let groups = group_by_control_number(records.into_iter());
+		assert_eq!(groups.len(), 3);
+		assert_eq!(groups["1"].len(), 2);
+		assert_eq!(groups["2"].len(), 2);
+		assert_eq!(groups["2"][1].control_number, " 2 ");
+		assert_eq!(groups[""].len(), 2);
+		assert_eq!(groups[""][1].control_number, "  ");
+	}
+
+	#[test]
+	fn groups_by_control_number_and_identifier() {
+		let oclc = |control_number: &str| {
+			Marc::from_bytes(&record(&[("001", control_number), ("003", "OCoLC ")])).unwrap()
+		};
+		let records = vec![marc("1"), oclc("1"), marc(" 1"), oclc("2"), marc("")];
+		let groups = group_by_control_number_and_identifier(records.into_iter());
+		let key = |identifier: &str, control_number: &str| {
+			(identifier.to_string(), control_number.to_string())
+		};
+		assert_eq!(groups.len(), 4);
+		assert_eq!(groups[&key("DLC", "1")].len(), 2);
+		assert_eq!(groups[&key("DLC", "1")][1].control_number, " 1");
+		assert_eq!(groups[&key("OCoLC", "1")].len(), 1);
+		assert_eq!(groups[&key("OCoLC", "2")].len(), 1);
+		assert_eq!(groups[&key("DLC", "")].len(), 1);
+	}
 }
@@ -0,0 +1,118 @@
+//! Where to find a resource online.
+
+use crate::blank_as_hash;
+use crate::field::Field;
+use num_enum::FromPrimitive;
+
+/// A link to the resource, a version of it, or something related to it, from field 856.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd856.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ElectronicLocation {
+	/// The address, e.g. `https://hdl.handle.net/2027/uc1.b3342759`, from subfield `u`.
+	/// Empty if the field doesn't have one.
+	pub uri: String,
+	/// Text to show for the link, from subfield `y`, or the materials specified in subfield `3` if there's no `y`.
+	pub link_text: Option<String>,
+	/// How the resource is accessed, from the first indicator.
+	pub access_method: AccessMethod,
+	/// What the link points to, from the second indicator.
+	pub relationship: Relationship,
+}
+
+impl ElectronicLocation {
+	/// Reads a field 856.
+	pub(crate) fn from_field_856(field: &Field) -> ElectronicLocation {
+		ElectronicLocation {
+			uri: field.subfield(b'u').unwrap_or_default().to_string(),
+			link_text: field
+				.subfield(b'y')
+				.or_else(|| field.subfield(b'3'))
+				.map(str::to_string),
+			access_method: AccessMethod::from_primitive(blank_as_hash(field.indicators[0])),
+			relationship: Relationship::from_primitive(blank_as_hash(field.indicators[1])),
+		}
+	}
+}
+
+/// How an electronic resource is accessed.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum AccessMethod {
+	/// # - No information provided
+	NoInformation = b'#',
+	/// 0 - Email
+	Email = b'0',
+	/// 1 - FTP
+	Ftp = b'1',
+	/// 2 - Remote login (Telnet)
+	Telnet = b'2',
+	/// 3 - Dial-up
+	DialUp = b'3',
+	/// 4 - HTTP
+	Http = b'4',
+	/// 7 - Method specified in subfield $2
+	SourceInSubfield2 = b'7',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// How an electronic location relates to the resource the record describes.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum Relationship {
+	/// # - No information provided
+	NoInformation = b'#',
+	/// 0 - Resource
+	Resource = b'0',
+	/// 1 - Version of resource
+	Version = b'1',
+	/// 2 - Related resource, such as a table of contents or cover image
+	Related = b'2',
+	/// 3 - Component part(s) of resource
+	ComponentPart = b'3',
+	/// 4 - Version of component part(s) of resource
+	VersionOfComponentPart = b'4',
+	/// 8 - No display constant generated
+	NoDisplayConstant = b'8',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn reads_http_link() {
+		let location = ElectronicLocation::from_field_856(&data_field(
+			"40$uhttps://hdl.handle.net/2027/uc1.b3342759$yFull text",
+		));
+		assert_eq!(
+			location,
+			ElectronicLocation {
+				uri: "https://hdl.handle.net/2027/uc1.b3342759".to_string(),
+				link_text: Some("Full text".to_string()),
+				access_method: AccessMethod::Http,
+				relationship: Relationship::Resource,
+			}
+		);
+	}
+
+	#[test]
+	fn falls_back_to_materials_specified() {
+		let location = ElectronicLocation::from_field_856(&data_field(
+			"42$3Cover image$uhttps://example.com/cover.jpg",
+		));
+		assert_eq!(location.link_text.as_deref(), Some("Cover image"));
+		assert_eq!(location.relationship, Relationship::Related);
+		let bare = ElectronicLocation::from_field_856(&data_field("  $uftp://example.com/a"));
+		assert_eq!(bare.link_text, None);
+		assert_eq!(bare.access_method, AccessMethod::NoInformation);
+	}
+}
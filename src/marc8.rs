@@ -0,0 +1,178 @@
+//! Decoding MARC-8, the legacy character encoding used by records whose leader position 9 is blank.
+//!
+//! Only the default character sets are covered: Basic Latin (ASCII) and the ANSEL extended Latin set.
+//! Escape sequences that switch to other sets (Greek, Cyrillic, CJK, ...) are skipped, and bytes in those sets are decoded as if they were Latin.
+//!
+//! Original documentation:
+//!
+//! <https://www.loc.gov/marc/specifications/speccharmarc8.html>
+
+/// Decodes MARC-8 text into a `String`.
+///
+/// In MARC-8 a combining diacritic comes before the letter it modifies, while in Unicode it comes after,
+/// so each run of diacritics is moved after the following character.
+/// The result is left decomposed, e.g. `é` comes out as `e` followed by U+0301.
+/// Bytes that don't map to anything become U+FFFD.
+pub fn decode_marc8(bytes: &[u8]) -> String {
+	let mut decoded = String::with_capacity(bytes.len());
+	let mut diacritics = Vec::new();
+	let mut bytes = bytes.iter().copied();
+	while let Some(byte) = bytes.next() {
+		if byte == ESCAPE {
+			// An escape sequence is any number of intermediate bytes followed by a final byte.
+			for byte in bytes.by_ref() {
+				if !(0x20..=0x2F).contains(&byte) {
+					break;
+				}
+			}
+			continue;
+		}
+		if let Some(diacritic) = combining(byte) {
+			diacritics.push(diacritic);
+			continue;
+		}
+		match byte {
+			0x00..=0x7F => decoded.push(byte as char),
+			_ => match spacing(byte) {
+				Some(character) => decoded.push(character),
+				None => continue,
+			},
+		}
+		decoded.extend(diacritics.drain(..));
+	}
+	decoded.extend(diacritics);
+	decoded
+}
+
+const ESCAPE: u8 = 0x1B;
+
+/// The non-combining characters above ASCII, from the ANSEL set and the C1 controls MARC-8 uses.
+/// `None` for the non-sort markers, which have no Unicode equivalent and are dropped.
+fn spacing(byte: u8) -> Option<char> {
+	Some(match byte {
+		0x88 | 0x89 => return None,
+		0x8D => '\u{200D}',
+		0x8E => '\u{200C}',
+		0xA1 => 'Ł',
+		0xA2 => 'Ø',
+		0xA3 => 'Đ',
+		0xA4 => 'Þ',
+		0xA5 => 'Æ',
+		0xA6 => 'Œ',
+		0xA7 => 'ʹ',
+		0xA8 => '·',
+		0xA9 => '♭',
+		0xAA => '®',
+		0xAB => '±',
+		0xAC => 'Ơ',
+		0xAD => 'Ư',
+		0xAE => 'ʼ',
+		0xB0 => 'ʻ',
+		0xB1 => 'ł',
+		0xB2 => 'ø',
+		0xB3 => 'đ',
+		0xB4 => 'þ',
+		0xB5 => 'æ',
+		0xB6 => 'œ',
+		0xB7 => 'ʺ',
+		0xB8 => 'ı',
+		0xB9 => '£',
+		0xBA => 'ð',
+		0xBC => 'ơ',
+		0xBD => 'ư',
+		0xC0 => '°',
+		0xC1 => 'ℓ',
+		0xC2 => '℗',
+		0xC3 => '©',
+		0xC4 => '♯',
+		0xC5 => '¿',
+		0xC6 => '¡',
+		0xC7 => 'ß',
+		0xC8 => '€',
+		_ => char::REPLACEMENT_CHARACTER,
+	})
+}
+
+/// The Unicode combining character for an ANSEL diacritic.
+fn combining(byte: u8) -> Option<char> {
+	Some(match byte {
+		0xE0 => '\u{0309}',
+		0xE1 => '\u{0300}',
+		0xE2 => '\u{0301}',
+		0xE3 => '\u{0302}',
+		0xE4 => '\u{0303}',
+		0xE5 => '\u{0304}',
+		0xE6 => '\u{0306}',
+		0xE7 => '\u{0307}',
+		0xE8 => '\u{0308}',
+		0xE9 => '\u{030C}',
+		0xEA => '\u{030A}',
+		0xEB => '\u{FE20}',
+		0xEC => '\u{FE21}',
+		0xED => '\u{0315}',
+		0xEE => '\u{030B}',
+		0xEF => '\u{0310}',
+		0xF0 => '\u{0327}',
+		0xF1 => '\u{0328}',
+		0xF2 => '\u{0323}',
+		0xF3 => '\u{0324}',
+		0xF4 => '\u{0325}',
+		0xF5 => '\u{0333}',
+		0xF6 => '\u{0332}',
+		0xF7 => '\u{0326}',
+		0xF8 => '\u{031C}',
+		0xF9 => '\u{032E}',
+		0xFA => '\u{FE22}',
+		0xFB => '\u{FE23}',
+		0xFE => '\u{0313}',
+		_ => return None,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn passes_ascii_through() {
+		assert_eq!(decode_marc8(b"The great gatsby /"), "The great gatsby /");
+	}
+
+	#[test]
+	fn moves_diacritics_after_their_letter() {
+		assert_eq!(decode_marc8(b"Caf\xE2e"), "Cafe\u{301}");
+		assert_eq!(decode_marc8(b"M\xE8unchen"), "Mu\u{308}nchen");
+		assert_eq!(decode_marc8(b"Fran\xF0cais"), "Franc\u{327}ais");
+		assert_eq!(decode_marc8(b"Espa\xE4na"), "Espan\u{303}a");
+		assert_eq!(decode_marc8(b"\xE1A la carte"), "A\u{300} la carte");
+		assert_eq!(decode_marc8(b"Dvo\xE9r\xE1ak"), "Dvor\u{30C}a\u{300}k");
+	}
+
+	#[test]
+	fn keeps_stacked_diacritics_in_order() {
+		assert_eq!(decode_marc8(b"Vi\xE2\xE3et"), "Vie\u{301}\u{302}t");
+	}
+
+	#[test]
+	fn decodes_spacing_characters() {
+		assert_eq!(decode_marc8(b"\xA1od\xE2z"), "Łodz\u{301}");
+		assert_eq!(decode_marc8(b"K\xB2benhavn"), "København");
+		assert_eq!(decode_marc8(b"\xC31994"), "©1994");
+		assert_eq!(decode_marc8(b"Stra\xC7e"), "Straße");
+	}
+
+	#[test]
+	fn skips_escape_sequences_and_non_sort_markers() {
+		assert_eq!(decode_marc8(b"\x88The \x89title\x1B(B"), "The title");
+	}
+
+	#[test]
+	fn replaces_unmapped_bytes() {
+		assert_eq!(decode_marc8(b"a\xFFb"), "a\u{FFFD}b");
+	}
+
+	#[test]
+	fn keeps_trailing_diacritic() {
+		assert_eq!(decode_marc8(b"a\xE2"), "a\u{301}");
+	}
+}
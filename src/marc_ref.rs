@@ -0,0 +1,136 @@
+//! A borrowed view of a record that reads fields straight out of the input buffer.
+
+use crate::directory::{parse_directory, DirectoryEntry};
+use crate::error::ParseError;
+use crate::leader::Leader;
+use crate::{strip_byte_order_mark, Marc, ParseOptions, FIELD_TERMINATOR};
+
+/// A record whose leader and directory have been parsed, but whose fields are still raw bytes in the input buffer.
+///
+/// This is cheap to build, so it suits scanning many records and only decoding the ones that are kept with [`MarcRef::to_owned`].
+#[derive(Debug, Clone)]
+pub struct MarcRef<'a> {
+	bytes: &'a [u8],
+	leader: Leader,
+	entries: Vec<DirectoryEntry>,
+	options: ParseOptions,
+}
+
+impl<'a> MarcRef<'a> {
+	/// Parses the leader and directory of a single record.
+	///
+	/// Fails for the same structural problems as [`Marc::from_bytes`], but doesn't look inside any field.
+	pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ParseError> {
+		MarcRef::from_bytes_with_options(bytes, ParseOptions::default())
+	}
+
+	/// Parses the leader and directory of a single record, leniently if `options.lossy` is set.
+	/// The options are kept for [`MarcRef::to_owned`].
+	///
+	/// A UTF-8 byte order mark before the leader is skipped, and isn't part of [`MarcRef::as_bytes`].
+	pub fn from_bytes_with_options(
+		bytes: &'a [u8],
+		options: ParseOptions,
+	) -> Result<Self, ParseError> {
+		MarcRef::parse(strip_byte_order_mark(bytes), options)
+	}
+
+	/// Parses a record that isn't at the start of a file, so mustn't have a byte order mark.
+	pub(crate) fn parse(bytes: &'a [u8], options: ParseOptions) -> Result<Self, ParseError> {
+		if bytes.len() < 24 {
+			return Err(ParseError::LeaderTooShort(bytes.len()));
+		}
+		let leader = Leader::from_bytes_with_options(bytes[..24].try_into().unwrap(), options)?;
+		let entries = parse_directory(bytes, leader.base_address_of_data, leader.record_length)?;
+		Ok(MarcRef {
+			bytes,
+			leader,
+			entries,
+			options,
+		})
+	}
+
+	/// The whole record, as it was given to [`MarcRef::from_bytes`].
+	pub fn as_bytes(&self) -> &'a [u8] {
+		self.bytes
+	}
+
+	/// The leader of the record.
+	pub fn leader(&self) -> &Leader {
+		&self.leader
+	}
+
+	/// The options the record was parsed with.
+	pub fn options(&self) -> ParseOptions {
+		self.options
+	}
+
+	/// The directory entries of the record, in the order they appear.
+	pub fn entries(&self) -> &[DirectoryEntry] {
+		&self.entries
+	}
+
+	/// The bytes of a field as they appear in the record, including its field terminator if it has one.
+	pub fn raw_field(&self, entry: &DirectoryEntry) -> &'a [u8] {
+		&self.bytes[entry.range(self.leader.base_address_of_data)]
+	}
+
+	/// The contents of the first field with the given tag, without its field terminator.
+	pub fn field(&self, tag: &str) -> Option<&'a [u8]> {
+		let entry = self
+			.entries
+			.iter()
+			.find(|entry| entry.tag == tag.as_bytes())?;
+		Some(match self.raw_field(entry) {
+			[data @ .., FIELD_TERMINATOR] => data,
+			data => data,
+		})
+	}
+
+	/// The control number of the record (field 001), borrowed from the input.
+	///
+	/// Empty if the record has no 001, or its 001 isn't valid UTF-8.
+	/// Control numbers are ASCII in practice, which reads the same in MARC-8.
+	pub fn control_number(&self) -> &'a str {
+		self.field("001")
+			.and_then(|data| std::str::from_utf8(data).ok())
+			.unwrap_or_default()
+	}
+
+	/// Decodes every field into an owned [`Marc`].
+	pub fn to_owned(&self) -> Result<Marc, ParseError> {
+		Marc::from_ref(self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::record;
+
+	#[test]
+	fn borrows_fields_from_input() {
+		let bytes = record(&[("001", "ocm14919759"), ("003", "OCoLC")]);
+		let record = MarcRef::from_bytes(&bytes).unwrap();
+		let control_number = record.control_number();
+		assert_eq!(control_number, "ocm14919759");
+		assert!(bytes.as_ptr_range().contains(&control_number.as_ptr()));
+		assert_eq!(record.field("003"), Some(&b"OCoLC"[..]));
+		assert_eq!(record.field("245"), None);
+		assert_eq!(record.entries().len(), 2);
+	}
+
+	#[test]
+	fn missing_control_number_is_empty() {
+		let bytes = record(&[("003", "DLC")]);
+		assert_eq!(MarcRef::from_bytes(&bytes).unwrap().control_number(), "");
+	}
+
+	#[test]
+	fn converts_to_owned() {
+		let bytes = record(&[("001", "1"), ("003", "DLC")]);
+		let marc = MarcRef::from_bytes(&bytes).unwrap().to_owned().unwrap();
+		assert_eq!(marc.control_number, "1");
+		assert_eq!(marc.control_number_identifier, "DLC");
+	}
+}
@@ -0,0 +1,313 @@
+//! Information about the material as a whole.
+//! Fills field 8.
+
+use crate::additional_material_characteristics::AdditionalMaterialCharacteristics;
+use crate::blank_as_hash;
+use crate::error::InvalidCode;
+use crate::leader::{BibliographicLevel, Leader, TypeOfRecord};
+use num_enum::FromPrimitive;
+
+/// The fixed-length data elements of a record.
+///
+/// Positions 18-34 hold the same material-specific values as positions 1-17 of field 006,
+/// so they're decoded into an `AdditionalMaterialCharacteristics`.
+/// 008 has no type code of its own; the variant is chosen from the leader instead.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd008.html>
+#[derive(Debug)]
+pub struct MaterialCharacteristics {
+	/// The date the record was first entered, as `yymmdd`.
+	/// Positions 0-5.
+	pub date_entered_on_file: String,
+	/// What kind of dates `date_1` and `date_2` are.
+	/// Position 6.
+	pub type_of_date: TypeOfDate,
+	/// The first date, usually the year of publication.
+	/// Positions 7-10.
+	pub date_1: String,
+	/// The second date, e.g. the year a serial ceased publication.
+	/// Positions 11-14.
+	pub date_2: String,
+	/// The MARC code of the country of publication, production or execution.
+	/// Positions 15-17.
+	pub place_of_publication: String,
+	/// The material-specific values.
+	/// Positions 18-34, laid out like positions 1-17 of field 006.
+	///
+	/// `None` for visual materials, which `AdditionalMaterialCharacteristics` doesn't model yet.
+	pub material: Option<AdditionalMaterialCharacteristics>,
+	/// The MARC code of the language of the item.
+	/// Positions 35-37.
+	pub language: String,
+	/// Whether the record was modified from its source, e.g. romanized.
+	/// Position 38.
+	pub modified_record: ModifiedRecord,
+	/// The kind of agency that created the record.
+	/// Position 39.
+	pub cataloging_source: CatalogingAgency,
+}
+
+impl MaterialCharacteristics {
+	/// Decodes a field 008, using the leader's type of record and bibliographic level to pick the material-specific layout.
+	///
+	/// Positions past the end of a short field decode to `NotCoded` or an empty string.
+	/// Visual materials get no `material`, as their positions 18-34 aren't modelled yet.
+	pub fn from_field_008(bytes: &[u8], leader: &Leader) -> Self {
+		let code = |position: usize| blank_as_hash(bytes.get(position).copied().unwrap_or(b'|'));
+		let text = |start: usize, end: usize| {
+			let end = end.min(bytes.len());
+			let start = start.min(end);
+			String::from_utf8_lossy(&bytes[start..end])
+				.trim_end()
+				.to_string()
+		};
+
+		let mut material = vec![material_type(leader)];
+		material.extend_from_slice(&bytes[18.min(bytes.len())..35.min(bytes.len())]);
+
+		MaterialCharacteristics {
+			date_entered_on_file: text(0, 6),
+			type_of_date: TypeOfDate::from_primitive(code(6)),
+			date_1: text(7, 11),
+			date_2: text(11, 15),
+			place_of_publication: text(15, 18),
+			material: AdditionalMaterialCharacteristics::from_field_006(&material),
+			language: text(35, 38),
+			modified_record: ModifiedRecord::from_primitive(code(38)),
+			cataloging_source: CatalogingAgency::from_primitive(code(39)),
+		}
+	}
+
+	/// A field 006 carrying this record's material-specific values, e.g. to describe it as an aspect of another record.
+	///
+	/// Positions 1-17 are 008 positions 18-34, re-encoded from `material`, and position 0 is the type of material the leader gave.
+	/// The other way round, positions 18-34 of an 008 are `to_field_006()[1..]` of the 006 to fold in.
+	/// `None` when there's no `material` to encode.
+	pub fn to_field_006(&self) -> Option<[u8; 18]> {
+		self.material
+			.as_ref()
+			.map(AdditionalMaterialCharacteristics::to_field_006)
+	}
+}
+
+/// An 008 can't be decoded from its 40 bytes alone, since its layout depends on the leader, so the leader comes along in the tuple.
+impl TryFrom<(&[u8; 40], &Leader)> for MaterialCharacteristics {
+	type Error = InvalidCode;
+
+	/// Decodes a whole field 008 like [`from_field_008`](MaterialCharacteristics::from_field_008),
+	/// with the length checked at compile time.
+	/// Fails with the leader's type of record if its positions 18-34 aren't modelled, e.g. for visual materials,
+	/// where `from_field_008` would leave `material` empty.
+	fn try_from((bytes, leader): (&[u8; 40], &Leader)) -> Result<Self, Self::Error> {
+		let fixed = MaterialCharacteristics::from_field_008(bytes, leader);
+		match fixed.material {
+			Some(_) => Ok(fixed),
+			None => Err(InvalidCode(leader.type_of_record.into())),
+		}
+	}
+}
+
+/// The 006 position 0 code matching a record's leader.
+/// Language material at a serial level is a continuing resource; otherwise the type of record codes line up.
+fn material_type(leader: &Leader) -> u8 {
+	match (leader.type_of_record, leader.bibliographic_level) {
+		(
+			TypeOfRecord::LanguageMaterial | TypeOfRecord::ManuscriptLanguageMaterial,
+			BibliographicLevel::SerialComponentPart
+			| BibliographicLevel::IntegratingResource
+			| BibliographicLevel::Serial,
+		) => b's',
+		(type_of_record, _) => type_of_record.into(),
+	}
+}
+
+/// What kind of dates are in positions 7-14.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum TypeOfDate {
+	/// b - No dates given; B.C. date involved
+	NoDatesBc = b'b',
+	/// c - Continuing resource currently published
+	CurrentlyPublished = b'c',
+	/// d - Continuing resource ceased publication
+	CeasedPublication = b'd',
+	/// e - Detailed date
+	Detailed = b'e',
+	/// i - Inclusive dates of collection
+	Inclusive = b'i',
+	/// k - Range of years of bulk of collection
+	Bulk = b'k',
+	/// m - Multiple dates
+	Multiple = b'm',
+	/// n - Dates unknown
+	Unknown = b'n',
+	/// p - Date of distribution/release/issue and production/recording session when different
+	Distribution = b'p',
+	/// q - Questionable date
+	Questionable = b'q',
+	/// r - Reprint/reissue date and original date
+	Reprint = b'r',
+	/// s - Single known date/probable date
+	Single = b's',
+	/// t - Publication date and copyright date
+	Copyright = b't',
+	/// u - Continuing resource status unknown
+	StatusUnknown = b'u',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// Whether the record was modified from its source.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum ModifiedRecord {
+	/// # - Not modified
+	None = b'#',
+	/// d - Dashed-on information omitted
+	DashedOnOmitted = b'd',
+	/// o - Completely romanized/printed cards romanized
+	RomanizedCardsRomanized = b'o',
+	/// r - Completely romanized/printed cards in script
+	RomanizedCardsInScript = b'r',
+	/// s - Shortened
+	Shortened = b's',
+	/// x - Missing characters
+	MissingCharacters = b'x',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The kind of agency that created the record.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum CatalogingAgency {
+	/// # - National bibliographic agency
+	National = b'#',
+	/// c - Cooperative cataloging program
+	Cooperative = b'c',
+	/// d - Other
+	Other = b'd',
+	/// u - Unknown
+	Unknown = b'u',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::additional_material_characteristics::{
+		AlphabetScript, ConferencePublication, FormOfItem, Frequency, Illustration, Index,
+		LiteraryForm, Regularity,
+	};
+
+	#[test]
+	fn decodes_book() {
+		let leader = Leader::from_bytes(b"00000nam a2200000 a 4500").unwrap();
+		let fixed = MaterialCharacteristics::from_field_008(
+			b"830222s1983    nyua          000 1 eng  ",
+			&leader,
+		);
+		assert_eq!(fixed.date_entered_on_file, "830222");
+		assert_eq!(fixed.type_of_date, TypeOfDate::Single);
+		assert_eq!(fixed.date_1, "1983");
+		assert_eq!(fixed.date_2, "");
+		assert_eq!(fixed.place_of_publication, "nyu");
+		assert_eq!(fixed.language, "eng");
+		assert_eq!(fixed.modified_record, ModifiedRecord::None);
+		assert_eq!(fixed.cataloging_source, CatalogingAgency::National);
+		match fixed.material {
+			Some(AdditionalMaterialCharacteristics::Book {
+				manuscript,
+				illustrations,
+				index,
+				literary_form,
+				..
+			}) => {
+				assert!(!manuscript);
+				assert_eq!(illustrations[0], Illustration::Some);
+				assert_eq!(index, Index::None);
+				assert_eq!(literary_form, LiteraryForm::Fiction);
+			}
+			other => panic!("expected a book, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn decodes_continuing_resource() {
+		let leader = Leader::from_bytes(b"00000cas a2200000 a 4500").unwrap();
+		let fixed = MaterialCharacteristics::from_field_008(
+			b"750101c19759999dcumr p o     0   a0eng d",
+			&leader,
+		);
+		assert_eq!(fixed.type_of_date, TypeOfDate::CurrentlyPublished);
+		assert_eq!(fixed.date_1, "1975");
+		assert_eq!(fixed.date_2, "9999");
+		assert_eq!(fixed.place_of_publication, "dcu");
+		assert_eq!(fixed.cataloging_source, CatalogingAgency::Other);
+		match fixed.material {
+			Some(AdditionalMaterialCharacteristics::ContinuingResources {
+				frequency,
+				regularity,
+				form_of_current,
+				conference_publication,
+				original_alphabet_or_script,
+				..
+			}) => {
+				assert_eq!(frequency, Frequency::Monthly);
+				assert_eq!(regularity, Regularity::Regular);
+				assert_eq!(form_of_current, FormOfItem::Online);
+				assert_eq!(conference_publication, ConferencePublication::NonConference);
+				assert_eq!(original_alphabet_or_script, AlphabetScript::BasicRoman);
+			}
+			other => panic!("expected a continuing resource, got {:?}", other),
+		}
+	}
+
+	#[test]
+	fn stamps_field_006_from_008() {
+		let leader = Leader::from_bytes(b"00000cas a2200000 a 4500").unwrap();
+		let field_008 = b"750101c19759999dcumr   o     0   a eng d";
+		let fixed = MaterialCharacteristics::from_field_008(field_008, &leader);
+		let field_006 = fixed.to_field_006().unwrap();
+		assert_eq!(field_006[0], b's');
+		assert_eq!(&field_006[1..], &field_008[18..35]);
+		assert_eq!(
+			AdditionalMaterialCharacteristics::from_field_006(&field_006),
+			fixed.material
+		);
+	}
+
+	#[test]
+	fn converts_from_40_byte_array() {
+		let book = Leader::from_bytes(b"00000nam a2200000 a 4500").unwrap();
+		let field = b"830222s1983    nyua          000 1 eng  ";
+		let fixed = MaterialCharacteristics::try_from((field, &book)).unwrap();
+		assert_eq!(fixed.date_1, "1983");
+		assert_eq!(
+			fixed.material,
+			MaterialCharacteristics::from_field_008(field, &book).material
+		);
+		let film = Leader::from_bytes(b"00000ngm a2200000 a 4500").unwrap();
+		assert_eq!(
+			MaterialCharacteristics::try_from((field, &film)).unwrap_err(),
+			InvalidCode(b'g')
+		);
+	}
+
+	#[test]
+	fn visual_materials_have_no_material_block() {
+		let leader = Leader::from_bytes(b"00000ngm a2200000 a 4500").unwrap();
+		let fixed = MaterialCharacteristics::from_field_008(b"830222s1983", &leader);
+		assert_eq!(fixed.date_1, "1983");
+		assert_eq!(fixed.language, "");
+		assert_eq!(fixed.cataloging_source, CatalogingAgency::NotCoded);
+		assert!(fixed.material.is_none());
+		assert!(fixed.to_field_006().is_none());
+	}
+}
@@ -0,0 +1,155 @@
+//! Random access to the records in a memory-mapped file, behind the `mmap` feature.
+
+use crate::error::ParseError;
+use crate::marc_ref::MarcRef;
+use crate::{strip_byte_order_mark, ParseOptions, RECORD_TERMINATOR};
+use memmap2::Mmap;
+use std::cell::OnceCell;
+use std::fs::File;
+use std::ops::Range;
+use std::path::Path;
+
+/// A memory-mapped file of records, read as [`MarcRef`] views without copying.
+///
+/// Records are split at each [`RECORD_TERMINATOR`], like [`MarcRecords`](crate::records::MarcRecords),
+/// after skipping a UTF-8 byte order mark at the start of the file.
+/// Iterating doesn't need to know where every record is, but [`len`](MarcMmap::len) and [`get`](MarcMmap::get) do,
+/// so the file is scanned once, the first time either is called.
+#[derive(Debug)]
+pub struct MarcMmap {
+	map: Mmap,
+	index: OnceCell<Vec<Range<usize>>>,
+	options: ParseOptions,
+}
+
+impl MarcMmap {
+	/// Maps the file at `path`.
+	///
+	/// As with any memory map, the file must not be modified while it's mapped;
+	/// if another process truncates it, reading a record may crash this one.
+	pub fn open(path: impl AsRef<Path>) -> std::io::Result<Self> {
+		MarcMmap::open_with_options(path, ParseOptions::default())
+	}
+
+	/// Maps the file at `path` like [`MarcMmap::open`], parsing each record with `options`.
+	pub fn open_with_options(
+		path: impl AsRef<Path>,
+		options: ParseOptions,
+	) -> std::io::Result<Self> {
+		let file = File::open(path)?;
+		// SAFETY: the caveat above is passed on to the caller.
+		let map = unsafe { Mmap::map(&file)? };
+		Ok(MarcMmap {
+			map,
+			index: OnceCell::new(),
+			options,
+		})
+	}
+
+	/// The whole mapped file.
+	pub fn as_bytes(&self) -> &[u8] {
+		&self.map
+	}
+
+	/// The number of records in the file.
+	pub fn len(&self) -> usize {
+		self.index().len()
+	}
+
+	/// Whether the file has no records.
+	pub fn is_empty(&self) -> bool {
+		self.records().is_empty()
+	}
+
+	/// The record at `index`, or `None` if there are only `index` records or fewer.
+	/// The record is an `Err` if its leader or directory is malformed.
+	pub fn get(&self, index: usize) -> Option<Result<MarcRef<'_>, ParseError>> {
+		let range = self.index().get(index)?.clone();
+		Some(MarcRef::parse(&self.records()[range], self.options))
+	}
+
+	/// Iterates over the records in order, without building the index.
+	pub fn iter(&self) -> impl Iterator<Item = Result<MarcRef<'_>, ParseError>> {
+		self.records()
+			.split_inclusive(|&byte| byte == RECORD_TERMINATOR)
+			.map(|record| MarcRef::parse(record, self.options))
+	}
+
+	/// The mapped file after any byte order mark, which the index is relative to.
+	fn records(&self) -> &[u8] {
+		strip_byte_order_mark(&self.map)
+	}
+
+	fn index(&self) -> &[Range<usize>] {
+		self.index.get_or_init(|| {
+			let mut start = 0;
+			self.records()
+				.split_inclusive(|&byte| byte == RECORD_TERMINATOR)
+				.map(|record| {
+					start += record.len();
+					start - record.len()..start
+				})
+				.collect()
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::{messy_records, record};
+
+	fn mapped(name: &str, control_numbers: &[&str]) -> MarcMmap {
+		let bytes: Vec<u8> = control_numbers
+			.iter()
+			.flat_map(|control_number| record(&[("001", control_number)]))
+			.collect();
+		mapped_bytes(name, &bytes, ParseOptions::default())
+	}
+
+	fn mapped_bytes(name: &str, bytes: &[u8], options: ParseOptions) -> MarcMmap {
+		let path = std::env::temp_dir().join(format!("marcr-{}-{}.mrc", name, std::process::id()));
+		std::fs::write(&path, bytes).unwrap();
+		let map = MarcMmap::open_with_options(&path, options).unwrap();
+		std::fs::remove_file(&path).unwrap();
+		map
+	}
+
+	#[test]
+	fn gets_records_by_index() {
+		let map = mapped("get", &["1", "2", "3"]);
+		assert_eq!(map.len(), 3);
+		assert_eq!(map.get(1).unwrap().unwrap().control_number(), "2");
+		assert_eq!(map.get(2).unwrap().unwrap().control_number(), "3");
+		assert!(map.get(3).is_none());
+	}
+
+	#[test]
+	fn iterates_without_index() {
+		let map = mapped("iter", &["1", "2"]);
+		let control_numbers: Vec<&str> = map
+			.iter()
+			.map(|record| record.unwrap().control_number())
+			.collect();
+		assert_eq!(control_numbers, ["1", "2"]);
+		assert!(map.index.get().is_none());
+	}
+
+	#[test]
+	fn lossy_map_recovers_messy_records() {
+		let map = mapped_bytes(
+			"lossy",
+			&messy_records(),
+			ParseOptions {
+				lossy: true,
+				..ParseOptions::default()
+			},
+		);
+		let control_numbers: Vec<String> = map
+			.iter()
+			.map(|record| record.unwrap().to_owned().unwrap().control_number)
+			.collect();
+		assert_eq!(control_numbers, ["1", "2"]);
+		assert!(map.get(1).unwrap().is_ok());
+	}
+}
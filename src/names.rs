@@ -0,0 +1,333 @@
+//! The people and organizations responsible for a resource.
+
+use crate::blank_as_hash;
+use crate::field::{trim_punctuation, Field};
+use num_enum::FromPrimitive;
+
+/// The main entry of a record: the person, organization or meeting chiefly responsible for the work.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/bd1xx.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum MainEntry {
+	/// A person or family, from field 100.
+	PersonalName(PersonalName),
+	/// An organization or jurisdiction, from field 110.
+	CorporateName(CorporateName),
+	/// A conference or other meeting, from field 111.
+	/// Records with this kind of main entry usually have
+	/// [`ConferencePublication::Conference`](crate::additional_material_characteristics::ConferencePublication::Conference) set in their 008.
+	MeetingName(MeetingName),
+}
+
+impl MainEntry {
+	/// Reads a field 100.
+	pub(crate) fn from_field_100(field: &Field) -> MainEntry {
+		MainEntry::PersonalName(PersonalName::from_field(field))
+	}
+
+	/// Reads a field 110.
+	pub(crate) fn from_field_110(field: &Field) -> MainEntry {
+		MainEntry::CorporateName(CorporateName::from_field(field))
+	}
+
+	/// Reads a field 111.
+	pub(crate) fn from_field_111(field: &Field) -> MainEntry {
+		MainEntry::MeetingName(MeetingName::from_field(field))
+	}
+}
+
+/// Another person, organization or meeting associated with the work, such as a co-author or contributor.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/bd70x75x.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum AddedEntry {
+	/// A person or family, from field 700.
+	PersonalName {
+		/// The name of the person or family.
+		name: PersonalName,
+		/// The title of a work by the person that's contained in the resource, from subfield `t`.
+		title: Option<String>,
+		/// Whether the work is contained in the resource, from the second indicator.
+		kind: TypeOfAddedEntry,
+	},
+	/// An organization or jurisdiction, from field 710.
+	CorporateName {
+		/// The name of the organization or jurisdiction.
+		name: CorporateName,
+		/// The title of a work by the organization that's contained in the resource, from subfield `t`.
+		title: Option<String>,
+		/// Whether the work is contained in the resource, from the second indicator.
+		kind: TypeOfAddedEntry,
+	},
+}
+
+impl AddedEntry {
+	/// Reads a field 700.
+	pub(crate) fn from_field_700(field: &Field) -> AddedEntry {
+		AddedEntry::PersonalName {
+			name: PersonalName::from_field(field),
+			title: field.subfield(b't').map(trim_punctuation),
+			kind: TypeOfAddedEntry::from_primitive(blank_as_hash(field.indicators[1])),
+		}
+	}
+
+	/// Reads a field 710.
+	pub(crate) fn from_field_710(field: &Field) -> AddedEntry {
+		AddedEntry::CorporateName {
+			name: CorporateName::from_field(field),
+			title: field.subfield(b't').map(trim_punctuation),
+			kind: TypeOfAddedEntry::from_primitive(blank_as_hash(field.indicators[1])),
+		}
+	}
+}
+
+/// The name of a person or family, as found in fields 100, 600 and 700.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PersonalName {
+	/// What kind of name this is, from the first indicator.
+	pub kind: TypeOfPersonalName,
+	/// The name, e.g. `Smith, John`, from subfield `a`.
+	pub name: String,
+	/// Titles and other words associated with the name, e.g. `Sir`, from subfield `c`.
+	pub titles: Vec<String>,
+	/// Dates associated with the name, e.g. `1920-1999`, from subfield `d`.
+	pub dates: Option<String>,
+	/// Terms describing the person's relationship to the work, e.g. `author`, from subfield `e`.
+	pub relator_terms: Vec<String>,
+	/// MARC relator codes, e.g. `aut`, from subfield `4`.
+	pub relator_codes: Vec<String>,
+}
+
+impl PersonalName {
+	/// Reads the name parts of a personal name field.
+	pub(crate) fn from_field(field: &Field) -> PersonalName {
+		let all = |code| field.subfields(code).map(trim_punctuation).collect();
+		PersonalName {
+			kind: TypeOfPersonalName::from_primitive(blank_as_hash(field.indicators[0])),
+			name: trim_punctuation(field.subfield(b'a').unwrap_or_default()),
+			titles: all(b'c'),
+			dates: field.subfield(b'd').map(trim_punctuation),
+			relator_terms: all(b'e'),
+			relator_codes: all(b'4'),
+		}
+	}
+}
+
+/// The name of an organization or jurisdiction, as found in fields 110, 610 and 710.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CorporateName {
+	/// What kind of name this is, from the first indicator.
+	pub kind: TypeOfCorporateName,
+	/// The name of the organization or jurisdiction, e.g. `United States`, from subfield `a`.
+	pub name: String,
+	/// Subordinate units, from the highest level down, e.g. `Congress`, `House`, from subfield `b`.
+	pub subordinate_units: Vec<String>,
+	/// Terms describing the organization's relationship to the work, e.g. `issuing body`, from subfield `e`.
+	pub relator_terms: Vec<String>,
+	/// MARC relator codes, e.g. `isb`, from subfield `4`.
+	pub relator_codes: Vec<String>,
+}
+
+impl CorporateName {
+	/// Reads the name parts of a corporate name field.
+	pub(crate) fn from_field(field: &Field) -> CorporateName {
+		let all = |code| field.subfields(code).map(trim_punctuation).collect();
+		CorporateName {
+			kind: TypeOfCorporateName::from_primitive(blank_as_hash(field.indicators[0])),
+			name: trim_punctuation(field.subfield(b'a').unwrap_or_default()),
+			subordinate_units: all(b'b'),
+			relator_terms: all(b'e'),
+			relator_codes: all(b'4'),
+		}
+	}
+}
+
+/// The name of a meeting, as found in fields 111, 611 and 711.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MeetingName {
+	/// What kind of name this is, from the first indicator.
+	pub kind: TypeOfCorporateName,
+	/// The name of the meeting, e.g. `International Conference on Machine Learning`, from subfield `a`.
+	pub name: String,
+	/// The numbers of the meeting, e.g. `(36th`, from subfield `n`.
+	pub numbers: Vec<String>,
+	/// The date of the meeting, e.g. `2019`, from subfield `d`.
+	pub date: Option<String>,
+	/// Where the meeting was held, e.g. `Long Beach, Calif.)`, from subfield `c`.
+	pub locations: Vec<String>,
+	/// Subordinate units of the meeting, e.g. `Workshop on Security`, from subfield `e`.
+	pub subordinate_units: Vec<String>,
+}
+
+impl MeetingName {
+	/// Reads the name parts of a meeting name field.
+	pub(crate) fn from_field(field: &Field) -> MeetingName {
+		let all = |code| field.subfields(code).map(trim_punctuation).collect();
+		MeetingName {
+			kind: TypeOfCorporateName::from_primitive(blank_as_hash(field.indicators[0])),
+			name: trim_punctuation(field.subfield(b'a').unwrap_or_default()),
+			numbers: all(b'n'),
+			date: field.subfield(b'd').map(trim_punctuation),
+			locations: all(b'c'),
+			subordinate_units: all(b'e'),
+		}
+	}
+}
+
+/// What kind of personal name an entry is.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum TypeOfPersonalName {
+	/// 0 - Forename
+	Forename = b'0',
+	/// 1 - Surname
+	Surname = b'1',
+	/// 3 - Family name
+	FamilyName = b'3',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// What kind of corporate or meeting name an entry is.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum TypeOfCorporateName {
+	/// 0 - Inverted name
+	InvertedName = b'0',
+	/// 1 - Jurisdiction name
+	JurisdictionName = b'1',
+	/// 2 - Name in direct order
+	DirectOrder = b'2',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// Whether an added entry is for a work contained in the resource.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum TypeOfAddedEntry {
+	/// # - No information provided
+	NoInformation = b'#',
+	/// 2 - Analytical entry: the resource contains the work named in the entry
+	Analytical = b'2',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn reads_personal_name() {
+		let entry = MainEntry::from_field_100(&data_field("1 $aSmith, John,$d1920-1999"));
+		assert_eq!(
+			entry,
+			MainEntry::PersonalName(PersonalName {
+				kind: TypeOfPersonalName::Surname,
+				name: "Smith, John".to_string(),
+				titles: vec![],
+				dates: Some("1920-1999".to_string()),
+				relator_terms: vec![],
+				relator_codes: vec![],
+			})
+		);
+	}
+
+	#[test]
+	fn collects_repeated_relators() {
+		let MainEntry::PersonalName(name) = MainEntry::from_field_100(&data_field(
+			"0 $aMadonna,$d1958-$eauthor,$eperformer.$4aut$4prf",
+		)) else {
+			panic!("expected a personal name");
+		};
+		assert_eq!(name.kind, TypeOfPersonalName::Forename);
+		assert_eq!(name.relator_terms, ["author", "performer."]);
+		assert_eq!(name.relator_codes, ["aut", "prf"]);
+	}
+
+	#[test]
+	fn reads_corporate_name_with_subordinate_units() {
+		let entry = MainEntry::from_field_110(&data_field(
+			"1 $aUnited States.$bCongress.$bHouse.$eissuing body.$4isb",
+		));
+		assert_eq!(
+			entry,
+			MainEntry::CorporateName(CorporateName {
+				kind: TypeOfCorporateName::JurisdictionName,
+				name: "United States.".to_string(),
+				subordinate_units: vec!["Congress.".to_string(), "House.".to_string()],
+				relator_terms: vec!["issuing body.".to_string()],
+				relator_codes: vec!["isb".to_string()],
+			})
+		);
+	}
+
+	#[test]
+	fn reads_meeting_name_with_repeated_numbers() {
+		let entry = MainEntry::from_field_111(&data_field(
+			"2 $aInternational Conference on Machine Learning$n(36th :$d2019 :$cLong Beach, Calif.)",
+		));
+		assert_eq!(
+			entry,
+			MainEntry::MeetingName(MeetingName {
+				kind: TypeOfCorporateName::DirectOrder,
+				name: "International Conference on Machine Learning".to_string(),
+				numbers: vec!["(36th".to_string()],
+				date: Some("2019".to_string()),
+				locations: vec!["Long Beach, Calif.)".to_string()],
+				subordinate_units: vec![],
+			})
+		);
+		let MainEntry::MeetingName(joint) = MainEntry::from_field_111(&data_field(
+			"2 $aJoint Meeting$n(1st :$n2nd)$eSteering Committee",
+		)) else {
+			panic!("expected a meeting name");
+		};
+		assert_eq!(joint.numbers, ["(1st", "2nd)"]);
+		assert_eq!(joint.subordinate_units, ["Steering Committee"]);
+	}
+
+	#[test]
+	fn reads_analytical_added_entry() {
+		let entry = AddedEntry::from_field_700(&data_field(
+			"12$aShakespeare, William,$d1564-1616.$tHamlet.",
+		));
+		let AddedEntry::PersonalName { name, title, kind } = entry else {
+			panic!("expected a personal name");
+		};
+		assert_eq!(name.name, "Shakespeare, William");
+		assert_eq!(name.dates.as_deref(), Some("1564-1616."));
+		assert_eq!(title.as_deref(), Some("Hamlet."));
+		assert_eq!(kind, TypeOfAddedEntry::Analytical);
+	}
+
+	#[test]
+	fn reads_added_corporate_name() {
+		let entry = AddedEntry::from_field_710(&data_field(
+			"2 $aNational Science Foundation (U.S.).$bDivision of Physics,$esponsoring body.$4spn",
+		));
+		assert_eq!(
+			entry,
+			AddedEntry::CorporateName {
+				name: CorporateName {
+					kind: TypeOfCorporateName::DirectOrder,
+					name: "National Science Foundation (U.S.).".to_string(),
+					subordinate_units: vec!["Division of Physics".to_string()],
+					relator_terms: vec!["sponsoring body.".to_string()],
+					relator_codes: vec!["spn".to_string()],
+				},
+				title: None,
+				kind: TypeOfAddedEntry::NoInformation,
+			}
+		);
+	}
+}
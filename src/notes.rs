@@ -0,0 +1,142 @@
+//! Notes catalogers make about a resource: its contents, summary and so on.
+
+use crate::blank_as_hash;
+use crate::field::{trim_punctuation, Field, Subfield};
+use num_enum::FromPrimitive;
+
+/// The contents of a resource, e.g. the chapters of a book or the tracks of an album, from field 505.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd505.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ContentsNote {
+	/// The note as it would be displayed, e.g. `Quark models / J. Rosner -- Introduction to gauge theories.`.
+	/// This is subfield `a` if there is one, otherwise every subfield joined with spaces.
+	pub raw: String,
+	/// The titles of the parts, from subfield `t`.
+	/// Only enhanced notes (second indicator `0`) split the titles out; basic notes leave this empty.
+	pub titles: Vec<String>,
+}
+
+impl ContentsNote {
+	/// Reads a field 505.
+	pub(crate) fn from_field_505(field: &Field) -> ContentsNote {
+		let raw = match field.subfield(b'a') {
+			Some(text) => text.to_string(),
+			None => field
+				.subfields
+				.iter()
+				.map(|Subfield { value, .. }| value.trim())
+				.collect::<Vec<_>>()
+				.join(" "),
+		};
+		let titles = if field.indicators[1] == b'0' {
+			field
+				.subfields(b't')
+				.map(|title| trim_punctuation(title.trim_end().trim_end_matches("--")))
+				.collect()
+		} else {
+			Vec::new()
+		};
+		ContentsNote { raw, titles }
+	}
+}
+
+/// A summary, review or abstract of a resource, from field 520.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd520.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Summary {
+	/// The summary, from subfield `a`.
+	pub text: String,
+	/// A longer version of the summary, from subfield `b`.
+	pub expansion: Option<String>,
+	/// What kind of summary this is, from the first indicator.
+	pub kind: SummaryKind,
+}
+
+impl Summary {
+	/// Reads a field 520.
+	pub(crate) fn from_field_520(field: &Field) -> Summary {
+		Summary {
+			text: field.subfield(b'a').unwrap_or_default().to_string(),
+			expansion: field.subfield(b'b').map(str::to_string),
+			kind: SummaryKind::from_primitive(blank_as_hash(field.indicators[0])),
+		}
+	}
+}
+
+/// What kind of summary a field 520 holds.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum SummaryKind {
+	/// # - Summary
+	Summary = b'#',
+	/// 0 - Subject
+	Subject = b'0',
+	/// 1 - Review
+	Review = b'1',
+	/// 2 - Scope and content
+	Scope = b'2',
+	/// 3 - Abstract
+	Abstract = b'3',
+	/// 4 - Content advice
+	ContentAdvice = b'4',
+	/// 8 - No display constant generated
+	NoDisplayConstant = b'8',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn reads_titles_of_enhanced_note() {
+		let note = ContentsNote::from_field_505(&data_field(
+			"00$tQuark models /$rJ. Rosner --$tIntroduction to gauge theories --$tThe standard model.",
+		));
+		assert_eq!(
+			note.titles,
+			[
+				"Quark models",
+				"Introduction to gauge theories",
+				"The standard model."
+			]
+		);
+		assert_eq!(
+			note.raw,
+			"Quark models / J. Rosner -- Introduction to gauge theories -- The standard model."
+		);
+	}
+
+	#[test]
+	fn keeps_basic_note_as_text() {
+		let note =
+			ContentsNote::from_field_505(&data_field("0 $aPt. 1. Introduction -- pt. 2. Methods."));
+		assert_eq!(note.raw, "Pt. 1. Introduction -- pt. 2. Methods.");
+		assert!(note.titles.is_empty());
+	}
+
+	#[test]
+	fn reads_summary_kind() {
+		let summary = Summary::from_field_520(&data_field(
+			"3 $aExamines the effects of light on sleep.$bA survey of 200 adults found...",
+		));
+		assert_eq!(summary.kind, SummaryKind::Abstract);
+		assert_eq!(summary.text, "Examines the effects of light on sleep.");
+		assert_eq!(
+			summary.expansion.as_deref(),
+			Some("A survey of 200 adults found...")
+		);
+		let plain = Summary::from_field_520(&data_field("  $aA boy befriends a dragon."));
+		assert_eq!(plain.kind, SummaryKind::Summary);
+		assert_eq!(plain.expansion, None);
+	}
+}
@@ -0,0 +1,736 @@
+//! Information about the physical carrier of the material.
+//! Fills field 7.
+
+use crate::blank_as_hash;
+use num_enum::FromPrimitive;
+
+/// Coded information about the carrier of the material, such as a disc's size or a file's color content.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd007.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PhysicalDescription007 {
+	/// An electronic resource, such as a computer disc or an online file.
+	/// <https://www.loc.gov/marc/bibliographic/concise/bd007c.html>
+	Electronic {
+		/// What kind of carrier it is.
+		/// Position 1.
+		specific_material_designation: ElectronicMaterialDesignation,
+		/// The color content.
+		/// Position 3.
+		color: Color,
+		/// The size of the carrier.
+		/// Position 4.
+		dimensions: ElectronicDimensions,
+		/// Whether there's sound.
+		/// Position 5.
+		sound: Sound,
+		/// The number of bits per pixel, e.g. `024`, or `mmm` for multiple depths.
+		/// Positions 6-8.
+		image_bit_depth: String,
+		/// Whether the resource has one file format or several.
+		/// Position 9.
+		file_formats: FileFormats,
+		/// Whether quality assurance targets are included.
+		/// Position 10.
+		quality_assurance_targets: QualityAssuranceTargets,
+		/// What the file was reproduced from.
+		/// Position 11.
+		antecedent: Antecedent,
+		/// How the file is compressed.
+		/// Position 12.
+		compression: Compression,
+		/// What the reformatted file is meant for.
+		/// Position 13.
+		reformatting_quality: ReformattingQuality,
+	},
+	/// A sound recording, such as a CD, LP or cassette.
+	/// <https://www.loc.gov/marc/bibliographic/concise/bd007s.html>
+	SoundRecording {
+		/// What kind of carrier it is.
+		/// Position 1.
+		specific_material_designation: SoundMaterialDesignation,
+		/// The playing speed.
+		/// Position 3.
+		speed: Speed,
+		/// Mono, stereo or surround.
+		/// Position 4.
+		playback_channels: PlaybackChannels,
+		/// The width of the groove of a disc or cylinder.
+		/// Position 5.
+		groove: Groove,
+		/// The size of the carrier.
+		/// Position 6.
+		dimensions: SoundDimensions,
+		/// The width of a tape.
+		/// Position 7.
+		tape_width: TapeWidth,
+		/// The number of tracks on a tape.
+		/// Position 8.
+		tape_configuration: TapeConfiguration,
+		/// Whether the carrier is a master, a mass-produced copy and so on.
+		/// Position 9.
+		kind_of_disc: KindOfDisc,
+		/// What the carrier is made of.
+		/// Position 10.
+		kind_of_material: KindOfMaterial,
+		/// How the groove of a disc or cylinder was cut.
+		/// Position 11.
+		kind_of_cutting: KindOfCutting,
+		/// Equalization or noise reduction needed to play the recording back.
+		/// Position 12.
+		special_playback_characteristics: SpecialPlayback,
+		/// How the sound was captured and stored.
+		/// Position 13.
+		capture_and_storage: CaptureAndStorage,
+	},
+}
+
+impl PhysicalDescription007 {
+	/// Decodes the bytes of a field 007.
+	///
+	/// Position 0, the category of material, selects the variant:
+	/// `c` is an electronic resource and `s` a sound recording.
+	/// Returns `None` if the field is empty or position 0 is not one of those codes
+	/// (the other categories, such as `v` for videorecordings, aren't modelled yet).
+	///
+	/// Positions past the end of a short field decode to each enum's `NotCoded` default.
+	/// Blanks are read as `#`, which is how the MARC documentation (and these enums) write them.
+	pub fn from_field_007(bytes: &[u8]) -> Option<Self> {
+		let mut padded = [b'|'; 14];
+		for (position, &byte) in padded.iter_mut().zip(bytes) {
+			*position = blank_as_hash(byte);
+		}
+		let bytes = padded;
+		Some(match bytes[0] {
+			b'c' => PhysicalDescription007::Electronic {
+				specific_material_designation: ElectronicMaterialDesignation::from_primitive(
+					bytes[1],
+				),
+				color: Color::from_primitive(bytes[3]),
+				dimensions: ElectronicDimensions::from_primitive(bytes[4]),
+				sound: Sound::from_primitive(bytes[5]),
+				image_bit_depth: String::from_utf8_lossy(&bytes[6..9]).into_owned(),
+				file_formats: FileFormats::from_primitive(bytes[9]),
+				quality_assurance_targets: QualityAssuranceTargets::from_primitive(bytes[10]),
+				antecedent: Antecedent::from_primitive(bytes[11]),
+				compression: Compression::from_primitive(bytes[12]),
+				reformatting_quality: ReformattingQuality::from_primitive(bytes[13]),
+			},
+			b's' => PhysicalDescription007::SoundRecording {
+				specific_material_designation: SoundMaterialDesignation::from_primitive(bytes[1]),
+				speed: Speed::from_primitive(bytes[3]),
+				playback_channels: PlaybackChannels::from_primitive(bytes[4]),
+				groove: Groove::from_primitive(bytes[5]),
+				dimensions: SoundDimensions::from_primitive(bytes[6]),
+				tape_width: TapeWidth::from_primitive(bytes[7]),
+				tape_configuration: TapeConfiguration::from_primitive(bytes[8]),
+				kind_of_disc: KindOfDisc::from_primitive(bytes[9]),
+				kind_of_material: KindOfMaterial::from_primitive(bytes[10]),
+				kind_of_cutting: KindOfCutting::from_primitive(bytes[11]),
+				special_playback_characteristics: SpecialPlayback::from_primitive(bytes[12]),
+				capture_and_storage: CaptureAndStorage::from_primitive(bytes[13]),
+			},
+			_ => return None,
+		})
+	}
+}
+
+/// The kind of carrier of an electronic resource.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum ElectronicMaterialDesignation {
+	/// a - Tape cartridge
+	TapeCartridge = b'a',
+	/// b - Chip cartridge
+	ChipCartridge = b'b',
+	/// c - Computer optical disc cartridge
+	OpticalDiscCartridge = b'c',
+	/// d - Computer disc, type unspecified
+	Disc = b'd',
+	/// e - Computer disc cartridge, type unspecified
+	DiscCartridge = b'e',
+	/// f - Tape cassette
+	TapeCassette = b'f',
+	/// h - Tape reel
+	TapeReel = b'h',
+	/// j - Magnetic disk
+	MagneticDisk = b'j',
+	/// k - Computer card
+	Card = b'k',
+	/// m - Magneto-optical disc
+	MagnetoOpticalDisc = b'm',
+	/// o - Optical disc
+	OpticalDisc = b'o',
+	/// r - Remote
+	Remote = b'r',
+	/// s - Standalone device
+	StandaloneDevice = b's',
+	/// u - Unspecified
+	Unspecified = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The color content of an electronic resource.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum Color {
+	/// a - One color
+	OneColor = b'a',
+	/// b - Black-and-white
+	BlackAndWhite = b'b',
+	/// c - Multicolored
+	Multicolored = b'c',
+	/// g - Gray scale
+	GrayScale = b'g',
+	/// m - Mixed
+	Mixed = b'm',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The size of the carrier of an electronic resource.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum ElectronicDimensions {
+	/// a - 3 1/2 in.
+	ThreeAndAHalfInches = b'a',
+	/// e - 12 in.
+	TwelveInches = b'e',
+	/// g - 4 3/4 in. or 12 cm.
+	FourAndThreeQuartersInches = b'g',
+	/// i - 1 1/8 x 2 3/8 in.
+	OneAndAnEighthByTwoAndThreeEighthsInches = b'i',
+	/// j - 3 7/8 x 2 1/2 in.
+	ThreeAndSevenEighthsByTwoAndAHalfInches = b'j',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// o - 5 1/4 in.
+	FiveAndAQuarterInches = b'o',
+	/// u - Unknown
+	Unknown = b'u',
+	/// v - 8 in.
+	EightInches = b'v',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// Whether an electronic resource has sound.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum Sound {
+	/// # - No sound (silent)
+	Silent = b'#',
+	/// a - Sound
+	Sound = b'a',
+	/// u - Unknown
+	Unknown = b'u',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// Whether an electronic resource has one file format or several.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum FileFormats {
+	/// a - One file format
+	One = b'a',
+	/// m - Multiple file formats
+	Multiple = b'm',
+	/// u - Unknown
+	Unknown = b'u',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// Whether an electronic resource includes quality assurance targets.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum QualityAssuranceTargets {
+	/// a - Absent
+	Absent = b'a',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// p - Present
+	Present = b'p',
+	/// u - Unknown
+	Unknown = b'u',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// What an electronic resource was reproduced from.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum Antecedent {
+	/// a - File reproduced from original
+	Original = b'a',
+	/// b - File reproduced from microform
+	Microform = b'b',
+	/// c - File reproduced from an electronic resource
+	ElectronicResource = b'c',
+	/// d - File reproduced from an intermediate (not microform)
+	Intermediate = b'd',
+	/// m - Mixed
+	Mixed = b'm',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// u - Unknown
+	Unknown = b'u',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// How an electronic resource is compressed.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum Compression {
+	/// a - Uncompressed
+	Uncompressed = b'a',
+	/// b - Lossless
+	Lossless = b'b',
+	/// d - Lossy
+	Lossy = b'd',
+	/// m - Mixed
+	Mixed = b'm',
+	/// u - Unknown
+	Unknown = b'u',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// What a reformatted electronic resource is meant for.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum ReformattingQuality {
+	/// a - Access
+	Access = b'a',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// p - Preservation
+	Preservation = b'p',
+	/// r - Replacement
+	Replacement = b'r',
+	/// u - Unknown
+	Unknown = b'u',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The kind of carrier of a sound recording.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum SoundMaterialDesignation {
+	/// d - Sound disc
+	Disc = b'd',
+	/// e - Cylinder
+	Cylinder = b'e',
+	/// g - Sound cartridge
+	Cartridge = b'g',
+	/// i - Sound-track film
+	SoundTrackFilm = b'i',
+	/// q - Roll
+	Roll = b'q',
+	/// r - Remote
+	Remote = b'r',
+	/// s - Sound cassette
+	Cassette = b's',
+	/// t - Sound-tape reel
+	TapeReel = b't',
+	/// u - Unspecified
+	Unspecified = b'u',
+	/// w - Wire recording
+	WireRecording = b'w',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The playing speed of a sound recording.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum Speed {
+	/// a - 16 rpm (discs)
+	Rpm16 = b'a',
+	/// b - 33 1/3 rpm (discs)
+	Rpm33 = b'b',
+	/// c - 78 rpm (discs)
+	Rpm78 = b'c',
+	/// d - 45 rpm (discs)
+	Rpm45 = b'd',
+	/// f - 1.4 m. per second (discs), the speed of a compact disc
+	MetersPerSecond1_4 = b'f',
+	/// h - 120 rpm (cylinders)
+	Rpm120 = b'h',
+	/// i - 160 rpm (cylinders)
+	Rpm160 = b'i',
+	/// k - 15/16 ips (tapes)
+	Ips15_16 = b'k',
+	/// l - 1 7/8 ips (tapes)
+	Ips1_7_8 = b'l',
+	/// m - 3 3/4 ips (tapes)
+	Ips3_3_4 = b'm',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// o - 7 1/2 ips (tapes)
+	Ips7_1_2 = b'o',
+	/// p - 15 ips (tapes)
+	Ips15 = b'p',
+	/// r - 30 ips (tapes)
+	Ips30 = b'r',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The playback channels of a sound recording.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum PlaybackChannels {
+	/// m - Monaural
+	Monaural = b'm',
+	/// q - Quadraphonic, multichannel, or surround
+	Surround = b'q',
+	/// s - Stereophonic
+	Stereophonic = b's',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The groove width of a disc or the groove pitch of a cylinder.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum Groove {
+	/// m - Microgroove/fine
+	Microgroove = b'm',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// s - Coarse/standard
+	Coarse = b's',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The size of the carrier of a sound recording.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum SoundDimensions {
+	/// a - 3 in. diameter
+	ThreeInches = b'a',
+	/// b - 5 in. diameter
+	FiveInches = b'b',
+	/// c - 7 in. diameter
+	SevenInches = b'c',
+	/// d - 10 in. diameter
+	TenInches = b'd',
+	/// e - 12 in. diameter
+	TwelveInches = b'e',
+	/// f - 16 in. diameter
+	SixteenInches = b'f',
+	/// g - 4 3/4 in. (12 cm.) diameter
+	FourAndThreeQuartersInches = b'g',
+	/// j - 3 7/8 x 2 1/2 in.
+	ThreeAndSevenEighthsByTwoAndAHalfInches = b'j',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// o - 5 1/4 x 3 7/8 in.
+	FiveAndAQuarterByThreeAndSevenEighthsInches = b'o',
+	/// s - 2 3/4 x 4 in.
+	TwoAndThreeQuartersByFourInches = b's',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The width of a sound tape.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum TapeWidth {
+	/// l - 1/8 in.
+	OneEighthInch = b'l',
+	/// m - 1/4 in.
+	OneQuarterInch = b'm',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// o - 1/2 in.
+	OneHalfInch = b'o',
+	/// p - 1 in.
+	OneInch = b'p',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// The number of tracks on a sound tape.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum TapeConfiguration {
+	/// a - Full (1) track
+	FullTrack = b'a',
+	/// b - Half (2) track
+	HalfTrack = b'b',
+	/// c - Quarter (4) track
+	QuarterTrack = b'c',
+	/// d - Eight track
+	EightTrack = b'd',
+	/// e - Twelve track
+	TwelveTrack = b'e',
+	/// f - Sixteen track
+	SixteenTrack = b'f',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// Where a disc, cylinder or tape sits in the production process.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum KindOfDisc {
+	/// a - Master tape
+	MasterTape = b'a',
+	/// b - Tape duplication master
+	TapeDuplicationMaster = b'b',
+	/// d - Disc master (negative)
+	DiscMaster = b'd',
+	/// i - Instantaneous (recorded on the spot)
+	Instantaneous = b'i',
+	/// m - Mass-produced
+	MassProduced = b'm',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// r - Mother (positive)
+	Mother = b'r',
+	/// s - Stamper (negative)
+	Stamper = b's',
+	/// t - Test pressing
+	TestPressing = b't',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// What a sound carrier is made of.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum KindOfMaterial {
+	/// a - Lacquer coating
+	LacquerCoating = b'a',
+	/// b - Cellulose nitrate
+	CelluloseNitrate = b'b',
+	/// c - Acetate tape with ferrous oxide
+	AcetateTape = b'c',
+	/// g - Glass with lacquer
+	GlassWithLacquer = b'g',
+	/// i - Aluminum with lacquer
+	AluminumWithLacquer = b'i',
+	/// l - Metal
+	Metal = b'l',
+	/// m - Plastic with metal
+	PlasticWithMetal = b'm',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// p - Plastic
+	Plastic = b'p',
+	/// r - Paper with lacquer or ferrous oxide
+	Paper = b'r',
+	/// s - Shellac
+	Shellac = b's',
+	/// u - Unknown
+	Unknown = b'u',
+	/// w - Wax
+	Wax = b'w',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// How the groove of a disc or cylinder was cut.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum KindOfCutting {
+	/// h - Hill-and-dale cutting
+	HillAndDale = b'h',
+	/// l - Lateral or combined cutting
+	Lateral = b'l',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// u - Unknown
+	Unknown = b'u',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// Equalization or noise reduction needed to play a sound recording back.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum SpecialPlayback {
+	/// a - NAB standard
+	Nab = b'a',
+	/// b - CCIR standard
+	Ccir = b'b',
+	/// c - Dolby-B encoded
+	DolbyB = b'c',
+	/// d - dbx encoded
+	Dbx = b'd',
+	/// e - Digital recording
+	Digital = b'e',
+	/// f - Dolby-A encoded
+	DolbyA = b'f',
+	/// g - Dolby-C encoded
+	DolbyC = b'g',
+	/// h - CX encoded
+	Cx = b'h',
+	/// n - Not applicable
+	NotApplicable = b'n',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// How a sound recording was captured and stored.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum CaptureAndStorage {
+	/// a - Acoustical capture, direct storage
+	Acoustical = b'a',
+	/// b - Direct storage, not acoustical
+	Direct = b'b',
+	/// d - Digital storage
+	Digital = b'd',
+	/// e - Analog electrical storage
+	AnalogElectrical = b'e',
+	/// u - Unknown
+	Unknown = b'u',
+	/// z - Other
+	Other = b'z',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn decodes_online_resource() {
+		assert_eq!(
+			PhysicalDescription007::from_field_007(b"cr cn 024auaua"),
+			Some(PhysicalDescription007::Electronic {
+				specific_material_designation: ElectronicMaterialDesignation::Remote,
+				color: Color::Multicolored,
+				dimensions: ElectronicDimensions::NotApplicable,
+				sound: Sound::Silent,
+				image_bit_depth: "024".to_string(),
+				file_formats: FileFormats::One,
+				quality_assurance_targets: QualityAssuranceTargets::Unknown,
+				antecedent: Antecedent::Original,
+				compression: Compression::Unknown,
+				reformatting_quality: ReformattingQuality::Access,
+			})
+		);
+	}
+
+	#[test]
+	fn decodes_compact_disc() {
+		let Some(PhysicalDescription007::SoundRecording {
+			specific_material_designation,
+			speed,
+			playback_channels,
+			dimensions,
+			kind_of_material,
+			capture_and_storage,
+			..
+		}) = PhysicalDescription007::from_field_007(b"sd fsngnnmmned")
+		else {
+			panic!("expected a sound recording");
+		};
+		assert_eq!(
+			specific_material_designation,
+			SoundMaterialDesignation::Disc
+		);
+		assert_eq!(speed, Speed::MetersPerSecond1_4);
+		assert_eq!(playback_channels, PlaybackChannels::Stereophonic);
+		assert_eq!(dimensions, SoundDimensions::FourAndThreeQuartersInches);
+		assert_eq!(kind_of_material, KindOfMaterial::PlasticWithMetal);
+		assert_eq!(capture_and_storage, CaptureAndStorage::Digital);
+	}
+
+	#[test]
+	fn short_field_is_not_coded() {
+		let Some(PhysicalDescription007::SoundRecording { speed, .. }) =
+			PhysicalDescription007::from_field_007(b"ss")
+		else {
+			panic!("expected a sound recording");
+		};
+		assert_eq!(speed, Speed::NotCoded);
+	}
+
+	#[test]
+	fn skips_unmodelled_categories() {
+		assert_eq!(PhysicalDescription007::from_field_007(b"vd cvaizq"), None);
+		assert_eq!(PhysicalDescription007::from_field_007(b""), None);
+	}
+}
@@ -0,0 +1,105 @@
+//! Where, by whom and when a resource was published.
+
+use crate::blank_as_hash;
+use crate::field::{trim_punctuation, Field};
+use num_enum::FromPrimitive;
+
+/// The place, publisher and date of publication, as recorded in field 260 (and 264 in RDA records).
+///
+/// Each part can repeat, e.g. a book published simultaneously in two cities.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd260.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PublicationInfo {
+	/// The places of publication, e.g. `New York`, from subfield `a`.
+	pub place: Vec<String>,
+	/// The names of the publishers, e.g. `C. Scribner's Sons`, from subfield `b`.
+	pub publisher: Vec<String>,
+	/// The dates of publication, e.g. `1925.`, from subfield `c`.
+	pub date: Vec<String>,
+}
+
+impl PublicationInfo {
+	/// Reads the place, publisher and date subfields shared by fields 260 and 264.
+	pub(crate) fn from_field(field: &Field) -> PublicationInfo {
+		let all = |code| field.subfields(code).map(trim_punctuation).collect();
+		PublicationInfo {
+			place: all(b'a'),
+			publisher: all(b'b'),
+			date: all(b'c'),
+		}
+	}
+}
+
+/// What a field 264 records, from its second indicator.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd264.html>
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum PublicationKind {
+	/// 0 - Production
+	Production = b'0',
+	/// 1 - Publication
+	Publication = b'1',
+	/// 2 - Distribution
+	Distribution = b'2',
+	/// 3 - Manufacture
+	Manufacture = b'3',
+	/// 4 - Copyright notice date
+	CopyrightNotice = b'4',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+impl PublicationKind {
+	/// Reads a field 264 as the kind of statement it is, with its place, publisher and date.
+	pub(crate) fn from_field_264(field: &Field) -> (PublicationKind, PublicationInfo) {
+		(
+			PublicationKind::from_primitive(blank_as_hash(field.indicators[1])),
+			PublicationInfo::from_field(field),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn reads_imprint() {
+		let publication =
+			PublicationInfo::from_field(&data_field("  $aNew York :$bC. Scribner's Sons,$c1925."));
+		assert_eq!(
+			publication,
+			PublicationInfo {
+				place: vec!["New York".to_string()],
+				publisher: vec!["C. Scribner's Sons".to_string()],
+				date: vec!["1925.".to_string()],
+			}
+		);
+	}
+
+	#[test]
+	fn keeps_repeated_places_and_publishers() {
+		let publication =
+			PublicationInfo::from_field(&data_field("  $aLondon ;$aNew York :$bRoutledge,$c2004."));
+		assert_eq!(publication.place, ["London", "New York"]);
+		assert_eq!(publication.publisher, ["Routledge"]);
+	}
+
+	#[test]
+	fn reads_kind_from_second_indicator() {
+		let (kind, info) = PublicationKind::from_field_264(&data_field(" 4$c\u{a9}2015"));
+		assert_eq!(kind, PublicationKind::CopyrightNotice);
+		assert_eq!(info.date, ["\u{a9}2015"]);
+		assert!(info.place.is_empty());
+		let (kind, _) = PublicationKind::from_field_264(&data_field("  $aLondon"));
+		assert_eq!(kind, PublicationKind::NotCoded);
+	}
+}
@@ -0,0 +1,346 @@
+//! Reading records one at a time from a stream, for files too large to hold in memory.
+
+use crate::error::ParseError;
+use crate::{parse_number, Marc, ParseOptions, BYTE_ORDER_MARK, RECORD_TERMINATOR};
+use chrono::NaiveDateTime;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+
+/// An iterator over the records in a stream, yielding one parse result per record.
+///
+/// Each record is read by its length, the first five bytes of its leader, so only one record is held in memory at a time.
+/// A length of `00000`, used for records too long for five digits, is read up to the next [`RECORD_TERMINATOR`] instead.
+/// If the length isn't a number, the rest of that record is skipped up to the next [`RECORD_TERMINATOR`] and an error is yielded in its place,
+/// unless parsing is lossy, in which case the record is read up to the terminator like a `00000` one.
+/// A UTF-8 byte order mark at the start of the stream is skipped.
+/// If the stream ends before a record is complete, a [`ParseError::TruncatedRecord`] is yielded and iteration stops;
+/// likewise after any I/O error.
+///
+/// Long-running imports can checkpoint [`byte_position`](MarcReader::byte_position) after each record
+/// and pick up from there with [`resume_at`](MarcReader::resume_at).
+#[derive(Debug)]
+pub struct MarcReader<R> {
+	reader: BufReader<R>,
+	position: u64,
+	started: bool,
+	done: bool,
+	options: ParseOptions,
+}
+
+impl<R: Read> MarcReader<R> {
+	/// Reads records from `reader`, which is buffered internally.
+	pub fn new(reader: R) -> Self {
+		MarcReader::with_options(reader, ParseOptions::default())
+	}
+
+	/// Reads records from `reader`, parsing each with `options`.
+	pub fn with_options(reader: R, options: ParseOptions) -> Self {
+		MarcReader {
+			reader: BufReader::new(reader),
+			position: 0,
+			started: false,
+			done: false,
+			options,
+		}
+	}
+
+	/// How many bytes of the stream have been consumed, which after a record is read is the offset of the next one.
+	///
+	/// This counts every byte taken from the stream, including a byte order mark and the bytes skipped after an unreadable length,
+	/// but not bytes the internal buffer has read ahead.
+	pub fn byte_position(&self) -> u64 {
+		self.position
+	}
+
+	/// Yields only the records changed after `since`, going by their latest transaction (field 005),
+	/// for pulling the deltas of an incremental sync.
+	///
+	/// Records with no 005, or one that can't be read, are yielded if `include_undated` is set and skipped otherwise.
+	/// Errors are passed through, so a record that fails to parse isn't silently lost.
+	pub fn changed_since(
+		self,
+		since: NaiveDateTime,
+		include_undated: bool,
+	) -> impl Iterator<Item = Result<Marc, ParseError>> {
+		self.filter(move |result| match result {
+			Ok(marc) => marc
+				.date_and_time_of_latest_record_transaction
+				.map_or(include_undated, |changed| changed > since),
+			Err(_) => true,
+		})
+	}
+
+	/// Reads up to `count` more bytes of the stream onto the end of `record`.
+	fn read_bytes(&mut self, count: u64, record: &mut Vec<u8>) -> std::io::Result<()> {
+		let read = (&mut self.reader).take(count).read_to_end(record)?;
+		self.position += read as u64;
+		Ok(())
+	}
+
+	/// Reads the rest of the stream up to and including the next [`RECORD_TERMINATOR`] onto the end of `record`.
+	fn read_to_terminator(&mut self, record: &mut Vec<u8>) -> std::io::Result<()> {
+		let read = self.reader.read_until(RECORD_TERMINATOR, record)?;
+		self.position += read as u64;
+		Ok(())
+	}
+
+	/// Reads the next record's bytes, including its length.
+	/// `Ok(None)` means the stream ended cleanly between records.
+	fn read_record(&mut self) -> Result<Option<Vec<u8>>, ParseError> {
+		let mut record = Vec::new();
+		self.read_bytes(5, &mut record)?;
+		if !self.started {
+			self.started = true;
+			if record.starts_with(BYTE_ORDER_MARK) {
+				record.drain(..BYTE_ORDER_MARK.len());
+				self.read_bytes(BYTE_ORDER_MARK.len() as u64, &mut record)?;
+			}
+		}
+		if record.is_empty() {
+			return Ok(None);
+		}
+		if record.len() < 5 {
+			self.done = true;
+			return Err(ParseError::TruncatedRecord {
+				expected: 5,
+				found: record.len(),
+			});
+		}
+
+		let length = match parse_number(&record, "record length") {
+			Ok(length) if (1..24).contains(&length) => Err(ParseError::LeaderTooShort(length)),
+			result => result,
+		};
+		let length = match length {
+			Ok(length) => length,
+			Err(_) if self.options.lossy => 0,
+			Err(error) => {
+				self.read_to_terminator(&mut record)?;
+				return Err(error);
+			}
+		};
+		if length == 0 {
+			self.read_to_terminator(&mut record)?;
+			return Ok(Some(record));
+		}
+		self.read_bytes(length as u64 - 5, &mut record)?;
+		if record.len() < length {
+			self.done = true;
+			return Err(ParseError::TruncatedRecord {
+				expected: length,
+				found: record.len(),
+			});
+		}
+		Ok(Some(record))
+	}
+}
+
+impl<R: Read + Seek> MarcReader<R> {
+	/// Seeks `reader` to `offset`, a [`byte_position`](MarcReader::byte_position) saved earlier,
+	/// and reads records from there, e.g. to restart an import after a crash without rereading what was already loaded.
+	///
+	/// A byte order mark is only looked for when `offset` is 0.
+	pub fn resume_at(reader: R, offset: u64) -> std::io::Result<Self> {
+		MarcReader::resume_at_with_options(reader, offset, ParseOptions::default())
+	}
+
+	/// Resumes reading like [`MarcReader::resume_at`], parsing each record with `options`.
+	pub fn resume_at_with_options(
+		mut reader: R,
+		offset: u64,
+		options: ParseOptions,
+	) -> std::io::Result<Self> {
+		reader.seek(SeekFrom::Start(offset))?;
+		let mut marc_reader = MarcReader::with_options(reader, options);
+		marc_reader.position = offset;
+		marc_reader.started = offset > 0;
+		Ok(marc_reader)
+	}
+}
+
+impl<R: Read> Iterator for MarcReader<R> {
+	type Item = Result<Marc, ParseError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		match self.read_record() {
+			Ok(Some(record)) => Some(Marc::from_bytes_with_options(&record, self.options)),
+			Ok(None) => {
+				self.done = true;
+				None
+			}
+			Err(error) => {
+				if matches!(error, ParseError::Io(_)) {
+					self.done = true;
+				}
+				Some(Err(error))
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::{messy_records, oversized_record, record};
+
+	/// Hands out one byte per read, so every record is split across reads.
+	struct OneByteAtATime<'a>(&'a [u8]);
+
+	impl Read for OneByteAtATime<'_> {
+		fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+			match (self.0.split_first(), buf.first_mut()) {
+				(Some((&byte, rest)), Some(slot)) => {
+					*slot = byte;
+					self.0 = rest;
+					Ok(1)
+				}
+				_ => Ok(0),
+			}
+		}
+	}
+
+	fn records(control_numbers: &[&str]) -> Vec<u8> {
+		control_numbers
+			.iter()
+			.flat_map(|control_number| record(&[("001", control_number)]))
+			.collect()
+	}
+
+	#[test]
+	fn reads_records_split_across_reads() {
+		let bytes = records(&["1", "2", "3"]);
+		let control_numbers: Vec<String> = MarcReader::new(OneByteAtATime(&bytes))
+			.map(|marc| marc.unwrap().control_number)
+			.collect();
+		assert_eq!(control_numbers, ["1", "2", "3"]);
+	}
+
+	#[test]
+	fn filters_records_changed_since() {
+		let mut bytes = Vec::new();
+		for (control_number, changed) in [
+			("1", "19940223151047.0"),
+			("2", "20240101000000.0"),
+			("3", "not a date"),
+			("4", "20240101000000.1"),
+		] {
+			bytes.extend(record(&[("001", control_number), ("005", changed)]));
+		}
+		bytes.extend(record(&[("001", "5")]));
+		bytes.extend(b"?????");
+		let since = NaiveDateTime::parse_from_str("20240101000000", "%Y%m%d%H%M%S").unwrap();
+		let changed = |include_undated| {
+			MarcReader::new(bytes.as_slice())
+				.changed_since(since, include_undated)
+				.map(|marc| marc.map(|marc| marc.control_number))
+				.collect::<Vec<_>>()
+		};
+		let dated = changed(false);
+		assert_eq!(dated.len(), 2);
+		assert_eq!(dated[0].as_deref().unwrap(), "4");
+		assert!(dated[1].is_err());
+		let all = changed(true);
+		assert_eq!(all.len(), 4);
+		assert_eq!(
+			all[..3]
+				.iter()
+				.map(|marc| marc.as_deref().unwrap())
+				.collect::<Vec<_>>(),
+			["3", "4", "5"]
+		);
+		assert!(all[3].is_err());
+	}
+
+	#[test]
+	fn resumes_at_saved_position() {
+		let mut bytes = b"\xEF\xBB\xBF".to_vec();
+		bytes.extend(records(&["1", "2"]));
+		let mut unreadable_length = record(&[("001", "3")]);
+		unreadable_length[..5].copy_from_slice(b"?????");
+		bytes.extend(unreadable_length);
+		bytes.extend(records(&["4", "5"]));
+
+		let mut reader = MarcReader::new(OneByteAtATime(&bytes));
+		assert_eq!(reader.byte_position(), 0);
+		assert_eq!(reader.next().unwrap().unwrap().control_number, "1");
+		assert_eq!(reader.next().unwrap().unwrap().control_number, "2");
+		assert!(reader.next().unwrap().is_err());
+		let saved = reader.byte_position();
+		assert_eq!(saved as usize, bytes.len() - records(&["4", "5"]).len());
+
+		let resumed = MarcReader::resume_at(std::io::Cursor::new(&bytes), saved).unwrap();
+		assert_eq!(resumed.byte_position(), saved);
+		let control_numbers: Vec<String> =
+			resumed.map(|marc| marc.unwrap().control_number).collect();
+		assert_eq!(control_numbers, ["4", "5"]);
+
+		let mut reader = MarcReader::new(bytes.as_slice());
+		reader.by_ref().for_each(drop);
+		assert_eq!(reader.byte_position() as usize, bytes.len());
+	}
+
+	#[test]
+	fn skips_byte_order_mark() {
+		let mut bytes = b"\xEF\xBB\xBF".to_vec();
+		bytes.extend(records(&["1", "2"]));
+		let control_numbers: Vec<String> = MarcReader::new(OneByteAtATime(&bytes))
+			.map(|marc| marc.unwrap().control_number)
+			.collect();
+		assert_eq!(control_numbers, ["1", "2"]);
+	}
+
+	#[test]
+	fn truncated_record_is_an_error_once() {
+		let mut bytes = records(&["1", "2"]);
+		bytes.truncate(bytes.len() - 10);
+		let results: Vec<_> = MarcReader::new(bytes.as_slice()).collect();
+		assert_eq!(results.len(), 2);
+		assert!(results[0].is_ok());
+		assert!(matches!(
+			results[1],
+			Err(ParseError::TruncatedRecord { found, expected }) if found == expected - 10
+		));
+	}
+
+	#[test]
+	fn reads_oversized_record_up_to_terminator() {
+		let mut bytes = oversized_record();
+		bytes.extend(records(&["2"]));
+		let results: Vec<_> = MarcReader::new(bytes.as_slice()).collect();
+		assert_eq!(results.len(), 2);
+		assert_eq!(results[0].as_ref().unwrap().control_number, "1");
+		assert_eq!(results[1].as_ref().unwrap().control_number, "2");
+	}
+
+	#[test]
+	fn skips_record_with_unreadable_length() {
+		let mut bytes = records(&["1", "2", "3"]);
+		let second = bytes.len() / 3;
+		bytes[second..second + 5].copy_from_slice(b"?????");
+		let results: Vec<_> = MarcReader::new(bytes.as_slice()).collect();
+		assert_eq!(results.len(), 3);
+		assert!(matches!(results[1], Err(ParseError::InvalidNumber { .. })));
+		assert_eq!(results[2].as_ref().unwrap().control_number, "3");
+	}
+
+	#[test]
+	fn lossy_reader_recovers_messy_records() {
+		let mut bytes = messy_records();
+		let mut unreadable_length = record(&[("001", "3")]);
+		unreadable_length[..5].copy_from_slice(b"?????");
+		bytes.extend(unreadable_length);
+		assert!(MarcReader::new(bytes.as_slice()).all(|marc| marc.is_err()));
+		let control_numbers: Vec<String> = MarcReader::with_options(
+			OneByteAtATime(&bytes),
+			ParseOptions {
+				lossy: true,
+				..ParseOptions::default()
+			},
+		)
+		.map(|marc| marc.unwrap().control_number)
+		.collect();
+		assert_eq!(control_numbers, ["1", "2", "3"]);
+	}
+}
@@ -0,0 +1,243 @@
+//! Reading several records out of one buffer, such as the contents of a `.mrc` file.
+
+use crate::error::ParseError;
+use crate::marc_ref::MarcRef;
+use crate::{parse_number, strip_byte_order_mark, Marc, ParseOptions, RECORD_TERMINATOR};
+
+/// An iterator over the records in a buffer, yielding one parse result per record.
+///
+/// Records are split at each [`RECORD_TERMINATOR`] rather than by the leader's record length,
+/// so a record with a damaged leader or directory yields an error and iteration carries on with the next one.
+/// Bytes after the last terminator are parsed as a final record, unless there are none.
+/// A UTF-8 byte order mark at the start of the buffer is skipped.
+#[derive(Debug, Clone)]
+pub struct MarcRecords<'a> {
+	remaining: &'a [u8],
+	options: ParseOptions,
+}
+
+impl<'a> MarcRecords<'a> {
+	/// Iterates over the records in `bytes`.
+	pub fn new(bytes: &'a [u8]) -> Self {
+		MarcRecords::with_options(bytes, ParseOptions::default())
+	}
+
+	/// Iterates over the records in `bytes`, parsing each with `options`.
+	pub fn with_options(bytes: &'a [u8], options: ParseOptions) -> Self {
+		MarcRecords {
+			remaining: strip_byte_order_mark(bytes),
+			options,
+		}
+	}
+}
+
+impl<'a> Iterator for MarcRecords<'a> {
+	type Item = Result<Marc, ParseError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining.is_empty() {
+			return None;
+		}
+		let end = self
+			.remaining
+			.iter()
+			.position(|&byte| byte == RECORD_TERMINATOR)
+			.map_or(self.remaining.len(), |position| position + 1);
+		let (record, rest) = self.remaining.split_at(end);
+		self.remaining = rest;
+		Some(MarcRef::parse(record, self.options).and_then(|record| record.to_owned()))
+	}
+}
+
+impl Marc {
+	/// Parses every record in `bytes`, such as the contents of a `.mrc` file.
+	///
+	/// A malformed record gives an `Err` in its place without affecting the records around it.
+	/// Use [`MarcRecords`] to process records one at a time instead.
+	pub fn parse_all(bytes: &[u8]) -> Vec<Result<Marc, ParseError>> {
+		Marc::parse_all_with_options(bytes, ParseOptions::default())
+	}
+
+	/// Parses every record in `bytes` like [`Marc::parse_all`], leniently if `options.lossy` is set.
+	pub fn parse_all_with_options(
+		bytes: &[u8],
+		options: ParseOptions,
+	) -> Vec<Result<Marc, ParseError>> {
+		MarcRecords::with_options(bytes, options).collect()
+	}
+}
+
+impl Marc {
+	/// Parses every record in `bytes`, where records may be separated by newlines as well as record terminators,
+	/// as in files that have been through line-based text tools.
+	///
+	/// A record whose leader gives a length ending at a record terminator or newline is taken by that length,
+	/// so newlines inside its fields don't split it.
+	/// Otherwise the record runs to the next record terminator or newline, whichever comes first.
+	/// Blank lines between records are skipped.
+	pub fn parse_all_line_delimited(bytes: &[u8]) -> Vec<Result<Marc, ParseError>> {
+		Marc::parse_all_line_delimited_with_options(bytes, ParseOptions::default())
+	}
+
+	/// Parses every record in `bytes` like [`Marc::parse_all_line_delimited`], leniently if `options.lossy` is set.
+	pub fn parse_all_line_delimited_with_options(
+		bytes: &[u8],
+		options: ParseOptions,
+	) -> Vec<Result<Marc, ParseError>> {
+		let mut remaining = strip_byte_order_mark(bytes);
+		let mut records = Vec::new();
+		loop {
+			while let [b'\r' | b'\n', rest @ ..] = remaining {
+				remaining = rest;
+			}
+			if remaining.is_empty() {
+				return records;
+			}
+			let by_length = remaining
+				.get(..5)
+				.and_then(|length| parse_number(length, "record length").ok())
+				.filter(|&length| length >= 24)
+				.filter(|&length| {
+					matches!(remaining.get(length - 1), Some(&RECORD_TERMINATOR | &b'\n'))
+				});
+			let end = by_length.unwrap_or_else(|| {
+				remaining
+					.iter()
+					.position(|&byte| byte == RECORD_TERMINATOR || byte == b'\n')
+					.map_or(remaining.len(), |position| position + 1)
+			});
+			let (mut record, rest) = remaining.split_at(end);
+			remaining = rest;
+			while let [line @ .., b'\r' | b'\n'] = record {
+				record = line;
+			}
+			records.push(MarcRef::parse(record, options).and_then(|record| record.to_owned()));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::{messy_records, oversized_record, record};
+
+	#[test]
+	fn parses_concatenated_records() {
+		let mut bytes = record(&[("001", "1")]);
+		bytes.extend(record(&[("001", "2")]));
+		bytes.extend(record(&[("001", "3")]));
+		let control_numbers: Vec<String> = Marc::parse_all(&bytes)
+			.into_iter()
+			.map(|marc| marc.unwrap().control_number)
+			.collect();
+		assert_eq!(control_numbers, ["1", "2", "3"]);
+	}
+
+	#[test]
+	fn continues_after_malformed_record() {
+		let mut bytes = record(&[("001", "1")]);
+		let mut broken = record(&[("001", "2")]);
+		broken[20..24].copy_from_slice(b"9999");
+		bytes.extend(broken);
+		bytes.extend(record(&[("001", "3")]));
+		let results = Marc::parse_all(&bytes);
+		assert_eq!(results.len(), 3);
+		assert_eq!(results[0].as_ref().unwrap().control_number, "1");
+		assert!(matches!(results[1], Err(ParseError::InvalidEntryMap(_))));
+		assert_eq!(results[2].as_ref().unwrap().control_number, "3");
+	}
+
+	#[test]
+	fn parses_unterminated_last_record() {
+		let mut bytes = record(&[("001", "1")]);
+		let mut last = record(&[("001", "2")]);
+		last.pop();
+		bytes.extend(last);
+		let results = Marc::parse_all(&bytes);
+		assert_eq!(results.len(), 2);
+		assert_eq!(results[1].as_ref().unwrap().control_number, "2");
+	}
+
+	#[test]
+	fn splits_oversized_records_at_terminator() {
+		let mut bytes = oversized_record();
+		bytes.extend(record(&[("001", "2")]));
+		let results = Marc::parse_all(&bytes);
+		assert_eq!(results.len(), 2);
+		let oversized = results[0].as_ref().unwrap();
+		assert_eq!(oversized.leader.record_length, 0);
+		assert_eq!(oversized.control_number, "1");
+		assert_eq!(results[1].as_ref().unwrap().control_number, "2");
+	}
+
+	#[test]
+	fn skips_byte_order_mark_only_at_start() {
+		let mut bytes = b"\xEF\xBB\xBF".to_vec();
+		bytes.extend(record(&[("001", "1")]));
+		bytes.extend(b"\xEF\xBB\xBF");
+		bytes.extend(record(&[("001", "2")]));
+		let results = Marc::parse_all(&bytes);
+		assert_eq!(results.len(), 2);
+		assert_eq!(results[0].as_ref().unwrap().control_number, "1");
+		assert!(results[1].is_err());
+	}
+
+	#[test]
+	fn splits_records_at_newlines() {
+		let mut bytes = Vec::new();
+		for control_number in ["1", "2", "3"] {
+			let mut record = record(&[("001", control_number)]);
+			assert_eq!(record.pop(), Some(RECORD_TERMINATOR));
+			// A wrong length forces the fall back to newline splitting.
+			record[0..5].copy_from_slice(b"00000");
+			bytes.extend(record);
+			bytes.extend(b"\r\n");
+		}
+		bytes.extend(b"\n");
+		let results = Marc::parse_all_line_delimited(&bytes);
+		let control_numbers: Vec<&str> = results
+			.iter()
+			.map(|marc| marc.as_ref().unwrap().control_number.as_str())
+			.collect();
+		assert_eq!(control_numbers, ["1", "2", "3"]);
+	}
+
+	#[test]
+	fn keeps_newlines_inside_fields_when_length_is_given() {
+		let mut bytes = record(&[("001", "1"), ("500", "  $aFirst line\nsecond line.")]);
+		bytes.push(b'\n');
+		bytes.extend(record(&[("001", "2")]));
+		bytes.push(b'\n');
+		let results = Marc::parse_all_line_delimited(&bytes);
+		assert_eq!(results.len(), 2);
+		let first = results[0].as_ref().unwrap();
+		assert_eq!(first.control_number, "1");
+		assert!(first.warnings.is_empty());
+		assert_eq!(results[1].as_ref().unwrap().control_number, "2");
+	}
+
+	#[test]
+	fn lossy_options_recover_messy_records() {
+		let bytes = messy_records();
+		assert!(Marc::parse_all(&bytes).iter().all(Result::is_err));
+		let lossy = ParseOptions {
+			lossy: true,
+			..ParseOptions::default()
+		};
+		for results in [
+			Marc::parse_all_with_options(&bytes, lossy),
+			Marc::parse_all_line_delimited_with_options(&bytes, lossy),
+		] {
+			let control_numbers: Vec<String> = results
+				.into_iter()
+				.map(|marc| marc.unwrap().control_number)
+				.collect();
+			assert_eq!(control_numbers, ["1", "2"]);
+		}
+	}
+
+	#[test]
+	fn empty_buffer_has_no_records() {
+		assert!(Marc::parse_all(&[]).is_empty());
+	}
+}
@@ -0,0 +1,173 @@
+//! Flattening records into documents for search indexes such as tantivy, behind the `search` feature.
+
+use crate::names::{AddedEntry, MainEntry};
+use crate::Marc;
+
+/// A record flattened into named text fields, one per field of a typical search index schema.
+///
+/// This is a plain struct rather than a tantivy `Document`, so the crate doesn't depend on any one search engine;
+/// map its fields onto your schema when adding it to the index.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SearchDoc {
+	/// The control number, from field 001, as a document ID.
+	pub id: String,
+	/// The title and its remainder, e.g. `The great gatsby : a novel`, from field 245.
+	pub title: Option<String>,
+	/// The main entry's name followed by the added entries' names, e.g. `Fitzgerald, F. Scott`.
+	pub authors: Vec<String>,
+	/// The topical and geographic subject headings, e.g. `Philosophy -- History`.
+	pub subjects: Vec<String>,
+	/// The valid ISBNs, normalized, e.g. `0684801523`.
+	pub isbns: Vec<String>,
+	/// The year of publication, from 008 date 1, or failing that the first year in a 260 or 264 date.
+	pub year: Option<u16>,
+	/// The language code, e.g. `eng`, from the 008, or failing that the first 041 `$a`.
+	pub language: Option<String>,
+	/// The general, bibliography and contents notes and the summaries, for full-text search.
+	pub notes: Vec<String>,
+}
+
+impl Marc {
+	/// Flattens the record into a [`SearchDoc`] for indexing.
+	pub fn to_search_doc(&self) -> SearchDoc {
+		let authors = self
+			.main_entry
+			.iter()
+			.map(|entry| match entry {
+				MainEntry::PersonalName(name) => &name.name,
+				MainEntry::CorporateName(name) => &name.name,
+				MainEntry::MeetingName(name) => &name.name,
+			})
+			.chain(self.added_entries.iter().map(|entry| match entry {
+				AddedEntry::PersonalName { name, .. } => &name.name,
+				AddedEntry::CorporateName { name, .. } => &name.name,
+			}))
+			.cloned()
+			.collect();
+		let year = self
+			.eight
+			.as_ref()
+			.and_then(|eight| first_year(&eight.date_1))
+			.or_else(|| {
+				self.imprints
+					.iter()
+					.chain(self.publications.iter().map(|(_, info)| info))
+					.flat_map(|info| &info.date)
+					.find_map(|date| first_year(date))
+			});
+		let language = self
+			.eight
+			.as_ref()
+			.map(|eight| eight.language.trim())
+			.filter(|language| !language.is_empty())
+			.or(self.languages.text.first().map(String::as_str))
+			.map(str::to_string);
+		let notes = self
+			.general_notes
+			.iter()
+			.chain(&self.bibliography_notes)
+			.chain(self.contents_notes.iter().map(|note| &note.raw))
+			.chain(
+				self.summaries
+					.iter()
+					.flat_map(|summary| std::iter::once(&summary.text).chain(&summary.expansion)),
+			)
+			.cloned()
+			.collect();
+		SearchDoc {
+			id: self.control_number.trim().to_string(),
+			title: self.title.as_ref().map(|title| match &title.remainder {
+				Some(remainder) => format!("{} : {}", title.title, remainder),
+				None => title.title.clone(),
+			}),
+			authors,
+			subjects: self
+				.topical_subjects
+				.iter()
+				.chain(&self.geographic_subjects)
+				.map(|subject| subject.heading())
+				.collect(),
+			isbns: self
+				.isbns
+				.iter()
+				.filter(|isbn| !isbn.canceled)
+				.map(|isbn| isbn.normalized.clone())
+				.collect(),
+			year,
+			language,
+			notes,
+		}
+	}
+}
+
+/// The first run of four digits in a date, e.g. 1925 in `c1925.` or `[1925?]`.
+fn first_year(date: &str) -> Option<u16> {
+	date.as_bytes()
+		.windows(4)
+		.find(|window| window.iter().all(u8::is_ascii_digit))
+		.and_then(|digits| std::str::from_utf8(digits).ok()?.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::record;
+
+	#[test]
+	fn flattens_record() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", " 123 "),
+			("008", "830222s1925    nyua          000 1 eng  "),
+			("020", "  $a0-684-80152-3 (pbk.)$z0000000000"),
+			("100", "1 $aFitzgerald, F. Scott,$d1896-1940."),
+			(
+				"245",
+				"14$aThe great gatsby :$ba novel /$cF. Scott Fitzgerald.",
+			),
+			("500", "  $aFirst published in 1925."),
+			("520", "  $aA novel of the Jazz Age."),
+			("650", " 0$aRich people$zNew York (State)$vFiction."),
+			("651", " 0$aLong Island (N.Y.)$vFiction."),
+			("700", "1 $aPerkins, Maxwell E."),
+		]))
+		.unwrap();
+		assert_eq!(
+			marc.to_search_doc(),
+			SearchDoc {
+				id: "123".to_string(),
+				title: Some("The great gatsby : a novel".to_string()),
+				authors: vec![
+					"Fitzgerald, F. Scott".to_string(),
+					"Perkins, Maxwell E.".to_string()
+				],
+				subjects: vec![
+					"Rich people -- New York (State) -- Fiction.".to_string(),
+					"Long Island (N.Y.) -- Fiction.".to_string()
+				],
+				isbns: vec!["0684801523".to_string()],
+				year: Some(1925),
+				language: Some("eng".to_string()),
+				notes: vec![
+					"First published in 1925.".to_string(),
+					"A novel of the Jazz Age.".to_string()
+				],
+			}
+		);
+	}
+
+	#[test]
+	fn falls_back_to_imprint_and_041() {
+		let marc = Marc::from_bytes(&record(&[
+			("001", "1"),
+			("041", "0 $afre"),
+			("245", "00$aSans date"),
+			("260", "  $aParis :$bGallimard,$cc1950."),
+		]))
+		.unwrap();
+		let doc = marc.to_search_doc();
+		assert_eq!(doc.year, Some(1950));
+		assert_eq!(doc.language.as_deref(), Some("fre"));
+		assert!(doc.authors.is_empty());
+		assert!(doc.notes.is_empty());
+	}
+}
@@ -0,0 +1,347 @@
+//! Reading records one at a time from an async stream, behind the `tokio` feature.
+
+use crate::error::ParseError;
+use crate::{parse_number, Marc, ParseOptions, BYTE_ORDER_MARK, RECORD_TERMINATOR};
+use futures_core::Stream;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncSeek, AsyncSeekExt, ReadBuf};
+
+/// The async counterpart of [`MarcReader`](crate::reader::MarcReader), yielding one parse result per record.
+///
+/// Records are read by their length in the same way, with the same handling of a leading byte order mark,
+/// `00000` lengths, unreadable lengths (including when parsing is lossy), truncation and I/O errors.
+/// Each read asks for no more than the rest of the current record, so wrap unbuffered sources like sockets in a
+/// [`BufReader`](tokio::io::BufReader).
+/// Progress can be checkpointed and resumed as with the blocking reader,
+/// using [`byte_position`](MarcStream::byte_position) and [`resume_at`](MarcStream::resume_at).
+#[derive(Debug)]
+pub struct MarcStream<R> {
+	reader: R,
+	position: u64,
+	record: Vec<u8>,
+	state: State,
+	started: bool,
+	options: ParseOptions,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum State {
+	/// Reading the five-byte record length.
+	Length,
+	/// Reading the rest of a record of the given length.
+	Record(usize),
+	/// Reading a record with a length of `00000` up to its record terminator.
+	Scan,
+	/// Skipping to the next record terminator after an unreadable length.
+	Skip,
+	/// The stream has ended or failed.
+	Done,
+}
+
+impl<R: AsyncRead + Unpin> MarcStream<R> {
+	/// Reads records from `reader`.
+	pub fn new(reader: R) -> Self {
+		MarcStream::with_options(reader, ParseOptions::default())
+	}
+
+	/// Reads records from `reader`, parsing each with `options`.
+	pub fn with_options(reader: R, options: ParseOptions) -> Self {
+		MarcStream {
+			reader,
+			position: 0,
+			record: Vec::new(),
+			state: State::Length,
+			started: false,
+			options,
+		}
+	}
+
+	/// How many bytes of the stream have been consumed, which after a record is yielded is the offset of the next one.
+	///
+	/// Like [`MarcReader::byte_position`](crate::reader::MarcReader::byte_position), this counts a byte order mark and skipped bytes.
+	/// After an unreadable length is yielded as an error, the rest of that record hasn't been skipped yet,
+	/// so save positions after records that were read.
+	pub fn byte_position(&self) -> u64 {
+		self.position
+	}
+
+	/// Reads into `record` until it's `target` bytes long, returning how many bytes were read (0 at the end of the stream).
+	fn poll_fill(&mut self, cx: &mut Context<'_>, target: usize) -> Poll<std::io::Result<usize>> {
+		let start = self.record.len();
+		self.record.resize(target, 0);
+		let mut buf = ReadBuf::new(&mut self.record[start..]);
+		let result = Pin::new(&mut self.reader).poll_read(cx, &mut buf);
+		let read = buf.filled().len();
+		self.record.truncate(start + read);
+		self.position += read as u64;
+		result.map_ok(|()| read)
+	}
+}
+
+impl<R: AsyncRead + AsyncSeek + Unpin> MarcStream<R> {
+	/// Seeks `reader` to `offset`, a [`byte_position`](MarcStream::byte_position) saved earlier, and reads records from there.
+	///
+	/// A byte order mark is only looked for when `offset` is 0.
+	pub async fn resume_at(reader: R, offset: u64) -> std::io::Result<Self> {
+		MarcStream::resume_at_with_options(reader, offset, ParseOptions::default()).await
+	}
+
+	/// Resumes reading like [`MarcStream::resume_at`], parsing each record with `options`.
+	pub async fn resume_at_with_options(
+		mut reader: R,
+		offset: u64,
+		options: ParseOptions,
+	) -> std::io::Result<Self> {
+		reader.seek(std::io::SeekFrom::Start(offset)).await?;
+		let mut stream = MarcStream::with_options(reader, options);
+		stream.position = offset;
+		stream.started = offset > 0;
+		Ok(stream)
+	}
+}
+
+impl<R: AsyncRead + Unpin> Stream for MarcStream<R> {
+	type Item = Result<Marc, ParseError>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		let this = self.get_mut();
+		loop {
+			let target = match this.state {
+				State::Done => return Poll::Ready(None),
+				State::Length => 5,
+				State::Record(length) => length,
+				State::Scan => {
+					let read = ready!(this.poll_fill(cx, this.record.len() + 1));
+					match read {
+						Ok(0) => this.state = State::Done,
+						Ok(_) if this.record.last() != Some(&RECORD_TERMINATOR) => continue,
+						Ok(_) => this.state = State::Length,
+						Err(error) => {
+							this.state = State::Done;
+							return Poll::Ready(Some(Err(error.into())));
+						}
+					}
+					let record = std::mem::take(&mut this.record);
+					return Poll::Ready(Some(Marc::from_bytes_with_options(&record, this.options)));
+				}
+				State::Skip => {
+					this.record.clear();
+					let read = ready!(this.poll_fill(cx, 1));
+					let skipped = this.record.pop();
+					match read {
+						Ok(0) => this.state = State::Done,
+						Ok(_) if skipped == Some(RECORD_TERMINATOR) => this.state = State::Length,
+						Ok(_) => {}
+						Err(error) => {
+							this.state = State::Done;
+							return Poll::Ready(Some(Err(error.into())));
+						}
+					}
+					continue;
+				}
+			};
+
+			if this.record.len() < target {
+				match ready!(this.poll_fill(cx, target)) {
+					Ok(0) => {
+						let found = this.record.len();
+						this.state = State::Done;
+						if found == 0 && target == 5 {
+							return Poll::Ready(None);
+						}
+						return Poll::Ready(Some(Err(ParseError::TruncatedRecord {
+							expected: target,
+							found,
+						})));
+					}
+					Ok(_) => continue,
+					Err(error) => {
+						this.state = State::Done;
+						return Poll::Ready(Some(Err(error.into())));
+					}
+				}
+			}
+
+			if this.state == State::Length {
+				if !this.started {
+					this.started = true;
+					if this.record.starts_with(BYTE_ORDER_MARK) {
+						this.record.drain(..BYTE_ORDER_MARK.len());
+						continue;
+					}
+				}
+				match parse_number(&this.record, "record length") {
+					Ok(0) => this.state = State::Scan,
+					Ok(length) if length >= 24 => this.state = State::Record(length),
+					_ if this.options.lossy => this.state = State::Scan,
+					result => {
+						this.state = State::Skip;
+						let error = match result {
+							Ok(length) => ParseError::LeaderTooShort(length),
+							Err(error) => error,
+						};
+						return Poll::Ready(Some(Err(error)));
+					}
+				}
+			} else {
+				let record = std::mem::take(&mut this.record);
+				this.state = State::Length;
+				return Poll::Ready(Some(Marc::from_bytes_with_options(&record, this.options)));
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::{messy_records, oversized_record, record};
+	use futures_util::StreamExt;
+
+	/// Hands out a few bytes per read, and is only ready on every other poll.
+	struct Chunked<'a> {
+		bytes: &'a [u8],
+		ready: bool,
+	}
+
+	impl AsyncRead for Chunked<'_> {
+		fn poll_read(
+			mut self: Pin<&mut Self>,
+			cx: &mut Context<'_>,
+			buf: &mut ReadBuf<'_>,
+		) -> Poll<std::io::Result<()>> {
+			self.ready = !self.ready;
+			if !self.ready {
+				cx.waker().wake_by_ref();
+				return Poll::Pending;
+			}
+			let count = self.bytes.len().min(buf.remaining()).min(7);
+			let (chunk, rest) = self.bytes.split_at(count);
+			buf.put_slice(chunk);
+			self.bytes = rest;
+			Poll::Ready(Ok(()))
+		}
+	}
+
+	fn records(control_numbers: &[&str]) -> Vec<u8> {
+		control_numbers
+			.iter()
+			.flat_map(|control_number| record(&[("001", control_number)]))
+			.collect()
+	}
+
+	#[tokio::test]
+	async fn reads_records_in_chunks() {
+		let bytes = records(&["1", "2", "3"]);
+		let control_numbers: Vec<String> = MarcStream::new(Chunked {
+			bytes: &bytes,
+			ready: false,
+		})
+		.map(|marc| marc.unwrap().control_number)
+		.collect()
+		.await;
+		assert_eq!(control_numbers, ["1", "2", "3"]);
+	}
+
+	#[tokio::test]
+	async fn resumes_at_saved_position() {
+		let mut bytes = b"\xEF\xBB\xBF".to_vec();
+		bytes.extend(records(&["1", "2", "3", "4"]));
+
+		let mut stream = MarcStream::new(Chunked {
+			bytes: &bytes,
+			ready: false,
+		});
+		assert_eq!(stream.next().await.unwrap().unwrap().control_number, "1");
+		assert_eq!(stream.next().await.unwrap().unwrap().control_number, "2");
+		let saved = stream.byte_position();
+		assert_eq!(saved as usize, bytes.len() - records(&["3", "4"]).len());
+
+		let resumed = MarcStream::resume_at(std::io::Cursor::new(&bytes), saved)
+			.await
+			.unwrap();
+		assert_eq!(resumed.byte_position(), saved);
+		let control_numbers: Vec<String> = resumed
+			.map(|marc| marc.unwrap().control_number)
+			.collect()
+			.await;
+		assert_eq!(control_numbers, ["3", "4"]);
+
+		let mut bytes_with_skip = records(&["1", "2"]);
+		bytes_with_skip[..5].copy_from_slice(b"?????");
+		let mut stream = MarcStream::new(bytes_with_skip.as_slice());
+		while stream.next().await.is_some() {}
+		assert_eq!(stream.byte_position() as usize, bytes_with_skip.len());
+	}
+
+	#[tokio::test]
+	async fn skips_byte_order_mark() {
+		let mut bytes = b"\xEF\xBB\xBF".to_vec();
+		bytes.extend(records(&["1", "2"]));
+		let control_numbers: Vec<String> = MarcStream::new(Chunked {
+			bytes: &bytes,
+			ready: false,
+		})
+		.map(|marc| marc.unwrap().control_number)
+		.collect()
+		.await;
+		assert_eq!(control_numbers, ["1", "2"]);
+	}
+
+	#[tokio::test]
+	async fn truncated_record_is_an_error_once() {
+		let mut bytes = records(&["1", "2"]);
+		bytes.truncate(bytes.len() - 10);
+		let results: Vec<_> = MarcStream::new(bytes.as_slice()).collect().await;
+		assert_eq!(results.len(), 2);
+		assert!(results[0].is_ok());
+		assert!(matches!(
+			results[1],
+			Err(ParseError::TruncatedRecord { found, expected }) if found == expected - 10
+		));
+	}
+
+	#[tokio::test]
+	async fn reads_oversized_record_up_to_terminator() {
+		let mut bytes = oversized_record();
+		bytes.extend(records(&["2"]));
+		let results: Vec<_> = MarcStream::new(bytes.as_slice()).collect().await;
+		assert_eq!(results.len(), 2);
+		assert_eq!(results[0].as_ref().unwrap().control_number, "1");
+		assert_eq!(results[1].as_ref().unwrap().control_number, "2");
+	}
+
+	#[tokio::test]
+	async fn skips_record_with_unreadable_length() {
+		let mut bytes = records(&["1", "2", "3"]);
+		let second = bytes.len() / 3;
+		bytes[second..second + 5].copy_from_slice(b"?????");
+		let results: Vec<_> = MarcStream::new(bytes.as_slice()).collect().await;
+		assert_eq!(results.len(), 3);
+		assert!(matches!(results[1], Err(ParseError::InvalidNumber { .. })));
+		assert_eq!(results[2].as_ref().unwrap().control_number, "3");
+	}
+
+	#[tokio::test]
+	async fn lossy_stream_recovers_messy_records() {
+		let mut bytes = messy_records();
+		let mut unreadable_length = record(&[("001", "3")]);
+		unreadable_length[..5].copy_from_slice(b"?????");
+		bytes.extend(unreadable_length);
+		let control_numbers: Vec<String> = MarcStream::with_options(
+			Chunked {
+				bytes: &bytes,
+				ready: false,
+			},
+			ParseOptions {
+				lossy: true,
+				..ParseOptions::default()
+			},
+		)
+		.map(|marc| marc.unwrap().control_number)
+		.collect()
+		.await;
+		assert_eq!(control_numbers, ["1", "2", "3"]);
+	}
+}
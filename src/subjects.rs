@@ -0,0 +1,145 @@
+//! What a resource is about.
+
+use crate::blank_as_hash;
+use crate::field::{trim_punctuation, Field, Subfield};
+use num_enum::FromPrimitive;
+
+/// A subject heading, as found in fields 650 and 651.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd650.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SubjectHeading {
+	/// The main term, from subfield `a`.
+	/// A topic like `Philosophy` in field 650, or a place like `France` in field 651.
+	pub topic: String,
+	/// The subdivisions that narrow the heading, in the order they appear.
+	/// `Philosophy -- History -- Sources` and `Philosophy -- Sources -- History` mean different things.
+	pub subdivisions: Vec<Subdivision>,
+	/// Which thesaurus the heading comes from, from the second indicator.
+	pub thesaurus: SubjectThesaurus,
+	/// The code of the thesaurus, e.g. `fast`, from subfield `2`.
+	/// Usually only present when `thesaurus` is [`SubjectThesaurus::SourceInSubfield2`].
+	pub source: Option<String>,
+}
+
+impl SubjectHeading {
+	/// Reads a subject heading field.
+	pub(crate) fn from_field(field: &Field) -> SubjectHeading {
+		SubjectHeading {
+			topic: trim_punctuation(field.subfield(b'a').unwrap_or_default()),
+			subdivisions: field
+				.subfields
+				.iter()
+				.filter_map(|Subfield { code, value }| {
+					let value = trim_punctuation(value);
+					match code {
+						b'v' => Some(Subdivision::Form(value)),
+						b'x' => Some(Subdivision::General(value)),
+						b'y' => Some(Subdivision::Chronological(value)),
+						b'z' => Some(Subdivision::Geographic(value)),
+						_ => None,
+					}
+				})
+				.collect(),
+			thesaurus: SubjectThesaurus::from_primitive(blank_as_hash(field.indicators[1])),
+			source: field.subfield(b'2').map(str::to_string),
+		}
+	}
+
+	/// The heading as it's usually displayed, with the subdivisions after the topic, e.g. `Philosophy -- History -- Sources`.
+	pub fn heading(&self) -> String {
+		std::iter::once(self.topic.as_str())
+			.chain(self.subdivisions.iter().map(Subdivision::value))
+			.collect::<Vec<_>>()
+			.join(" -- ")
+	}
+}
+
+/// A subdivision of a subject heading.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Subdivision {
+	/// The form or genre of the resource, e.g. `Periodicals`, from subfield `v`.
+	Form(String),
+	/// A general aspect of the topic, e.g. `History`, from subfield `x`.
+	General(String),
+	/// A period of time, e.g. `20th century`, from subfield `y`.
+	Chronological(String),
+	/// A place, e.g. `France`, from subfield `z`.
+	Geographic(String),
+}
+
+impl Subdivision {
+	/// The text of the subdivision, whatever its kind.
+	pub fn value(&self) -> &str {
+		match self {
+			Subdivision::Form(value)
+			| Subdivision::General(value)
+			| Subdivision::Chronological(value)
+			| Subdivision::Geographic(value) => value,
+		}
+	}
+}
+
+/// The thesaurus a subject heading was taken from.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum SubjectThesaurus {
+	/// 0 - Library of Congress Subject Headings
+	Lcsh = b'0',
+	/// 1 - LC subject headings for children's literature
+	LcChildrens = b'1',
+	/// 2 - Medical Subject Headings
+	Mesh = b'2',
+	/// 3 - National Agricultural Library subject authority file
+	Nal = b'3',
+	/// 4 - Source not specified
+	NotSpecified = b'4',
+	/// 5 - Canadian Subject Headings
+	Canadian = b'5',
+	/// 6 - Répertoire de vedettes-matière
+	Rvm = b'6',
+	/// 7 - Source specified in subfield $2
+	SourceInSubfield2 = b'7',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn keeps_subdivisions_in_order() {
+		let heading = SubjectHeading::from_field(&data_field(
+			" 0$aPhilosophy$xHistory$yAncient, 600 B.C.-500 A.D.$vSources.",
+		));
+		assert_eq!(
+			heading,
+			SubjectHeading {
+				topic: "Philosophy".to_string(),
+				subdivisions: vec![
+					Subdivision::General("History".to_string()),
+					Subdivision::Chronological("Ancient, 600 B.C.-500 A.D.".to_string()),
+					Subdivision::Form("Sources.".to_string()),
+				],
+				thesaurus: SubjectThesaurus::Lcsh,
+				source: None,
+			}
+		);
+		assert_eq!(
+			heading.heading(),
+			"Philosophy -- History -- Ancient, 600 B.C.-500 A.D. -- Sources."
+		);
+	}
+
+	#[test]
+	fn reads_source_of_heading() {
+		let heading = SubjectHeading::from_field(&data_field(" 7$aDragons.$2fast"));
+		assert_eq!(heading.thesaurus, SubjectThesaurus::SourceInSubfield2);
+		assert_eq!(heading.source.as_deref(), Some("fast"));
+	}
+}
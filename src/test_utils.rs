@@ -0,0 +1,88 @@
+//! Helpers for building records in tests.
+
+use crate::field::Field;
+use crate::leader::Leader;
+use crate::{ParseOptions, FIELD_TERMINATOR, RECORD_TERMINATOR, SUBFIELD_DELIMITER};
+
+/// Assembles a record from `(tag, value)` pairs, with a leader for a UTF-8 book.
+/// In data field values, `$` stands in for the subfield delimiter.
+pub fn record(fields: &[(&str, &str)]) -> Vec<u8> {
+	let fields: Vec<(&str, Vec<u8>)> = fields
+		.iter()
+		.map(|(tag, value)| {
+			(
+				*tag,
+				value
+					.replace('$', &(SUBFIELD_DELIMITER as char).to_string())
+					.into_bytes(),
+			)
+		})
+		.collect();
+	let fields: Vec<(&str, &[u8])> = fields
+		.iter()
+		.map(|(tag, value)| (*tag, value.as_slice()))
+		.collect();
+	record_with_leader(b"00000nam a2200000   4500", &fields)
+}
+
+/// Assembles a record from raw field values, filling in the record length and base address of `leader`.
+/// Records longer than 99999 bytes get a length of `00000`.
+pub fn record_with_leader(leader: &[u8; 24], fields: &[(&str, &[u8])]) -> Vec<u8> {
+	let mut directory = Vec::new();
+	let mut data = Vec::new();
+	for (tag, value) in fields {
+		let start = data.len();
+		data.extend_from_slice(value);
+		data.push(FIELD_TERMINATOR);
+		directory
+			.extend_from_slice(format!("{}{:04}{:05}", tag, data.len() - start, start).as_bytes());
+	}
+	directory.push(FIELD_TERMINATOR);
+	data.push(RECORD_TERMINATOR);
+
+	let base_address = 24 + directory.len();
+	let mut record = leader.to_vec();
+	let length = base_address + data.len();
+	if length <= 99999 {
+		record[0..5].copy_from_slice(format!("{:05}", length).as_bytes());
+	} else {
+		record[0..5].copy_from_slice(b"00000");
+	}
+	record[12..17].copy_from_slice(format!("{:05}", base_address).as_bytes());
+	record.extend(directory);
+	record.extend(data);
+	record
+}
+
+/// Two records that only parse leniently: control number `1` has invalid UTF-8 in its title,
+/// and control number `2` has an undefined type of record in its leader.
+pub fn messy_records() -> Vec<u8> {
+	let mut bytes = record_with_leader(
+		b"00000nam a2200000   4500",
+		&[("001", b"1"), ("245", b"10\x1Fa\xFFtitle")],
+	);
+	bytes.extend(record_with_leader(
+		b"00000nxm a2200000   4500",
+		&[("001", b"2")],
+	));
+	bytes
+}
+
+/// A record longer than 99999 bytes, with control number `1` and eleven long contents notes.
+/// The last note starts just before offset 99999, the furthest a directory entry can point.
+pub fn oversized_record() -> Vec<u8> {
+	let note = format!("0 $a{}", "-- Chapter ".repeat(900))
+		.replace('$', &(SUBFIELD_DELIMITER as char).to_string());
+	let mut fields = vec![("001", "1".as_bytes())];
+	fields.extend(std::iter::repeat_n(("505", note.as_bytes()), 11));
+	let record = record_with_leader(b"00000nam a2200000   4500", &fields);
+	assert!(record.len() > 99999);
+	record
+}
+
+/// Decodes a data field from its indicators and subfields, with `$` standing in for the subfield delimiter.
+pub fn data_field(value: &str) -> Field {
+	let leader = Leader::from_bytes(b"00000nam a2200000   4500").unwrap();
+	let value = value.replace('$', &(SUBFIELD_DELIMITER as char).to_string());
+	Field::parse(&leader, ParseOptions::default(), "999", value.as_bytes()).unwrap()
+}
@@ -0,0 +1,357 @@
+//! The titles of a resource.
+
+use crate::blank_as_hash;
+use crate::field::{trim_punctuation, Field, Subfield};
+use num_enum::FromPrimitive;
+
+/// The title and statement of responsibility, from field 245.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd245.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TitleStatement {
+	/// The title proper, e.g. `The great gatsby`, from subfield `a`.
+	pub title: String,
+	/// The remainder of the title, such as a subtitle, from subfield `b`.
+	pub remainder: Option<String>,
+	/// The statement of responsibility, e.g. `F. Scott Fitzgerald.`, from subfield `c`.
+	pub statement_of_responsibility: Option<String>,
+	/// The number of characters at the start of the title to skip when sorting, e.g. 4 for `The `, from the second indicator.
+	pub nonfiling_chars: u8,
+	/// The part or section of a larger work the resource is, from subfields `n` and `p`.
+	/// `None` if the field has neither.
+	pub part_info: Option<PartInfo>,
+}
+
+impl TitleStatement {
+	/// Reads a field 245.
+	pub(crate) fn from_field_245(field: &Field) -> TitleStatement {
+		let parts: Vec<TitlePart> = field
+			.subfields
+			.iter()
+			.filter_map(|Subfield { code, value }| {
+				let value = trim_punctuation(value);
+				match code {
+					b'n' => Some(TitlePart::Number(value)),
+					b'p' => Some(TitlePart::Name(value)),
+					_ => None,
+				}
+			})
+			.collect();
+		TitleStatement {
+			title: trim_punctuation(field.subfield(b'a').unwrap_or_default()),
+			remainder: field.subfield(b'b').map(trim_punctuation),
+			statement_of_responsibility: field.subfield(b'c').map(trim_punctuation),
+			nonfiling_chars: nonfiling_chars(field.indicators[1]),
+			part_info: (!parts.is_empty()).then_some(PartInfo { parts }),
+		}
+	}
+
+	/// The title without its nonfiling characters, e.g. `great gatsby`, for sorting.
+	pub fn sort_key(&self) -> &str {
+		let skip = self
+			.title
+			.char_indices()
+			.nth(self.nonfiling_chars as usize)
+			.map_or(self.title.len(), |(index, _)| index);
+		&self.title[skip..]
+	}
+}
+
+/// The part of a multipart work or set a record describes, e.g. `Part 2. The two towers`.
+/// Records describing a set this way often have a bibliographic level of `c` (collection) or `s` (serial).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartInfo {
+	/// The part numbers and names, in the order they appear.
+	/// A part can be nested in another, e.g. `Series B`, `Volume 3`, `Maps`, so there can be several of each.
+	pub parts: Vec<TitlePart>,
+}
+
+impl PartInfo {
+	/// The part numbers, e.g. `Part 2`, in order.
+	pub fn numbers(&self) -> impl Iterator<Item = &str> {
+		self.parts.iter().filter_map(|part| match part {
+			TitlePart::Number(number) => Some(number.as_str()),
+			TitlePart::Name(_) => None,
+		})
+	}
+
+	/// The part names, e.g. `The two towers`, in order.
+	pub fn names(&self) -> impl Iterator<Item = &str> {
+		self.parts.iter().filter_map(|part| match part {
+			TitlePart::Name(name) => Some(name.as_str()),
+			TitlePart::Number(_) => None,
+		})
+	}
+}
+
+/// A number or name identifying part of a work.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TitlePart {
+	/// The number of the part, e.g. `Part 2` or `Volume 3`, from subfield `n`.
+	Number(String),
+	/// The name of the part, e.g. `The two towers`, from subfield `p`.
+	Name(String),
+}
+
+/// The title a work is known by, which brings together its editions and translations, from field 240.
+/// A record only has one alongside a main entry, which it completes.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd240.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UniformTitle {
+	/// The uniform title, e.g. `Hamlet.`, from subfield `a`.
+	pub title: String,
+	/// The language of the resource, when it's a translation, e.g. `French`, from subfield `l`.
+	pub language: Option<String>,
+	/// The number of characters at the start of the title to skip when sorting, from the second indicator.
+	pub nonfiling_chars: u8,
+}
+
+impl UniformTitle {
+	/// Reads a field 240.
+	pub(crate) fn from_field_240(field: &Field) -> UniformTitle {
+		UniformTitle {
+			title: trim_punctuation(field.subfield(b'a').unwrap_or_default()),
+			language: field.subfield(b'l').map(trim_punctuation),
+			nonfiling_chars: nonfiling_chars(field.indicators[1]),
+		}
+	}
+}
+
+/// Another title the resource is known by, such as a cover or spine title, from field 246.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd246.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VaryingTitle {
+	/// The title, e.g. `Great gatsby`, from subfield `a`.
+	pub title: String,
+	/// Which kind of title this is, from the second indicator.
+	pub kind: VaryingTitleKind,
+	/// Text to display before the title, e.g. `Title on container`, from subfield `i`.
+	pub note: Option<String>,
+	/// Whether a note should be displayed for the title, from the first indicator.
+	pub display_note: bool,
+}
+
+impl VaryingTitle {
+	/// Reads a field 246.
+	pub(crate) fn from_field_246(field: &Field) -> VaryingTitle {
+		VaryingTitle {
+			title: trim_punctuation(field.subfield(b'a').unwrap_or_default()),
+			kind: VaryingTitleKind::from_primitive(blank_as_hash(field.indicators[1])),
+			note: field.subfield(b'i').map(trim_punctuation),
+			display_note: matches!(field.indicators[0], b'0' | b'1'),
+		}
+	}
+}
+
+/// What kind of varying title a field 246 holds.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum VaryingTitleKind {
+	/// # - No type specified
+	NoTypeSpecified = b'#',
+	/// 0 - Portion of title
+	Portion = b'0',
+	/// 1 - Parallel title
+	Parallel = b'1',
+	/// 2 - Distinctive title
+	Distinctive = b'2',
+	/// 3 - Other title
+	Other = b'3',
+	/// 4 - Cover title
+	Cover = b'4',
+	/// 5 - Added title page title
+	AddedTitlePage = b'5',
+	/// 6 - Caption title
+	Caption = b'6',
+	/// 7 - Running title
+	Running = b'7',
+	/// 8 - Spine title
+	Spine = b'8',
+	/// | - No attempt to code
+	#[default]
+	NotCoded = b'|',
+}
+
+/// Reads a nonfiling characters indicator, which is 0 when blank or not a digit.
+fn nonfiling_chars(indicator: u8) -> u8 {
+	match indicator {
+		digit @ b'0'..=b'9' => digit - b'0',
+		_ => 0,
+	}
+}
+
+/// Reads the edition statement of a field 250, e.g. `2nd ed.`, with the remainder of the statement (subfield `b`) appended to subfield `a`.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd250.html>
+pub(crate) fn edition_from_field_250(field: &Field) -> String {
+	let parts: Vec<&str> = field
+		.subfields
+		.iter()
+		.filter(|Subfield { code, .. }| matches!(code, b'a' | b'b'))
+		.map(|Subfield { value, .. }| value.trim())
+		.collect();
+	trim_punctuation(&parts.join(" "))
+}
+
+/// A series the resource belongs to, as transcribed from the resource in field 490.
+///
+/// Original documentation:
+///
+/// <https://www.loc.gov/marc/bibliographic/concise/bd490.html>
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SeriesStatement {
+	/// The title of the series, e.g. `Penguin classics`, from subfield `a`.
+	pub title: String,
+	/// The number of the resource within the series, e.g. `v. 12`, from subfield `v`.
+	pub volume: Option<String>,
+	/// The ISSN of the series, from subfield `x`.
+	pub issn: Option<String>,
+	/// Whether the series is also traced in an 8XX series added entry, from the first indicator.
+	pub traced: bool,
+}
+
+impl SeriesStatement {
+	/// Reads a field 490.
+	pub(crate) fn from_field_490(field: &Field) -> SeriesStatement {
+		SeriesStatement {
+			title: trim_punctuation(field.subfield(b'a').unwrap_or_default()),
+			volume: field.subfield(b'v').map(trim_punctuation),
+			issn: field.subfield(b'x').map(trim_punctuation),
+			traced: field.indicators[0] == b'1',
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::test_utils::data_field;
+
+	#[test]
+	fn splits_title_and_statement_of_responsibility() {
+		let title = TitleStatement::from_field_245(&data_field(
+			"14$aThe great gatsby /$cF. Scott Fitzgerald.",
+		));
+		assert_eq!(
+			title,
+			TitleStatement {
+				title: "The great gatsby".to_string(),
+				remainder: None,
+				statement_of_responsibility: Some("F. Scott Fitzgerald.".to_string()),
+				nonfiling_chars: 4,
+				part_info: None,
+			}
+		);
+		assert_eq!(title.sort_key(), "great gatsby");
+	}
+
+	#[test]
+	fn keeps_parts_in_order() {
+		let title = TitleStatement::from_field_245(&data_field(
+			"00$aGeological survey.$nSeries B,$pMaps.$nVolume 3,$pCoastal sheets /$cprepared by the Survey.",
+		));
+		assert_eq!(title.title, "Geological survey.");
+		let parts = title.part_info.unwrap();
+		assert_eq!(
+			parts.parts,
+			[
+				TitlePart::Number("Series B".to_string()),
+				TitlePart::Name("Maps.".to_string()),
+				TitlePart::Number("Volume 3".to_string()),
+				TitlePart::Name("Coastal sheets".to_string()),
+			]
+		);
+		assert_eq!(
+			parts.numbers().collect::<Vec<_>>(),
+			["Series B", "Volume 3"]
+		);
+		assert_eq!(
+			parts.names().collect::<Vec<_>>(),
+			["Maps.", "Coastal sheets"]
+		);
+	}
+
+	#[test]
+	fn reads_remainder_of_title() {
+		let title =
+			TitleStatement::from_field_245(&data_field("00$aDune :$ba novel /$cFrank Herbert."));
+		assert_eq!(title.title, "Dune");
+		assert_eq!(title.remainder.as_deref(), Some("a novel"));
+		assert_eq!(title.sort_key(), "Dune");
+	}
+
+	#[test]
+	fn nonfiling_chars_count_characters_not_bytes() {
+		let title = TitleStatement::from_field_245(&data_field("12$aL\u{2019}\u{e9}tranger"));
+		assert_eq!(title.sort_key(), "\u{e9}tranger");
+		let short = TitleStatement::from_field_245(&data_field("19$aLe"));
+		assert_eq!(short.sort_key(), "");
+	}
+
+	#[test]
+	fn reads_uniform_title() {
+		assert_eq!(
+			UniformTitle::from_field_240(&data_field("10$aHamlet.$lFrench")),
+			UniformTitle {
+				title: "Hamlet.".to_string(),
+				language: Some("French".to_string()),
+				nonfiling_chars: 0,
+			}
+		);
+	}
+
+	#[test]
+	fn reads_varying_title() {
+		assert_eq!(
+			VaryingTitle::from_field_246(&data_field("18$iTitle on spine:$aGatsby")),
+			VaryingTitle {
+				title: "Gatsby".to_string(),
+				kind: VaryingTitleKind::Spine,
+				note: Some("Title on spine".to_string()),
+				display_note: true,
+			}
+		);
+		let portion = VaryingTitle::from_field_246(&data_field("30$aGreat gatsby"));
+		assert_eq!(portion.kind, VaryingTitleKind::Portion);
+		assert!(!portion.display_note);
+		let untyped = VaryingTitle::from_field_246(&data_field("3 $aGatsby"));
+		assert_eq!(untyped.kind, VaryingTitleKind::NoTypeSpecified);
+	}
+
+	#[test]
+	fn reads_edition() {
+		assert_eq!(
+			edition_from_field_250(&data_field("  $a2nd ed.")),
+			"2nd ed."
+		);
+		assert_eq!(
+			edition_from_field_250(&data_field("  $aRev. ed. /$brevised by J. Smith.")),
+			"Rev. ed. / revised by J. Smith."
+		);
+	}
+
+	#[test]
+	fn reads_series_statement() {
+		assert_eq!(
+			SeriesStatement::from_field_490(&data_field(
+				"1 $aLecture notes in mathematics,$x0075-8434 ;$vv. 1024"
+			)),
+			SeriesStatement {
+				title: "Lecture notes in mathematics".to_string(),
+				volume: Some("v. 1024".to_string()),
+				issn: Some("0075-8434".to_string()),
+				traced: true,
+			}
+		);
+	}
+}
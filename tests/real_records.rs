@@ -0,0 +1,178 @@
+//! Parses the real records in `src/test_data` through the public API only, and checks them against known values.
+//!
+//! See `src/test_data/README.md` for where the records come from.
+//! Neither source has a map or serial record, so those kinds aren't covered here yet.
+
+use std::io::Cursor;
+
+use marcr::additional_material_characteristics::AdditionalMaterialCharacteristics;
+use marcr::leader::{CharacterCodingScheme, TypeOfRecord};
+use marcr::marc_ref::MarcRef;
+use marcr::reader::MarcReader;
+use marcr::records::MarcRecords;
+use marcr::Marc;
+
+/// What a record is known to contain, read off the record by hand.
+struct Expected {
+	name: &'static str,
+	bytes: &'static [u8],
+	type_of_record: TypeOfRecord,
+	character_coding_scheme: CharacterCodingScheme,
+	control_number: &'static str,
+	field_count: usize,
+	title: &'static str,
+	language: &'static str,
+	date_1: &'static str,
+	isbns: usize,
+	has_006: bool,
+}
+
+const RECORDS: &[Expected] = &[
+	Expected {
+		name: "music_score.mrc",
+		bytes: include_bytes!("../src/test_data/music_score.mrc"),
+		type_of_record: TypeOfRecord::NonmusicalSoundRecording,
+		character_coding_scheme: CharacterCodingScheme::Ucs,
+		control_number: "233",
+		field_count: 78,
+		title: "The Arias for bass",
+		language: "ita",
+		date_1: "2013",
+		isbns: 2,
+		has_006: false,
+	},
+	Expected {
+		name: "ebook_marc8.mrc",
+		bytes: include_bytes!("../src/test_data/ebook_marc8.mrc"),
+		type_of_record: TypeOfRecord::LanguageMaterial,
+		character_coding_scheme: CharacterCodingScheme::Marc8,
+		control_number: "B301882",
+		field_count: 30,
+		title: "The\u{301}ories e\u{301}conomiques en 30 secondes",
+		language: "fre",
+		date_1: "2011",
+		isbns: 2,
+		has_006: true,
+	},
+];
+
+#[test]
+fn parses_known_values() {
+	for expected in RECORDS {
+		let marc = Marc::from_bytes(expected.bytes).unwrap();
+		let name = expected.name;
+		assert!(marc.warnings.is_empty(), "{}: {:?}", name, marc.warnings);
+		assert_eq!(marc.leader.record_length, expected.bytes.len(), "{}", name);
+		assert_eq!(
+			marc.leader.type_of_record, expected.type_of_record,
+			"{}",
+			name
+		);
+		assert_eq!(
+			marc.leader.character_coding_scheme, expected.character_coding_scheme,
+			"{}",
+			name
+		);
+		assert_eq!(marc.control_number, expected.control_number, "{}", name);
+		assert_eq!(marc.raw_fields.len(), expected.field_count, "{}", name);
+		assert_eq!(
+			marc.title.as_ref().unwrap().title,
+			expected.title,
+			"{}",
+			name
+		);
+		let fixed = marc.eight.as_ref().unwrap();
+		assert_eq!(fixed.language, expected.language, "{}", name);
+		assert_eq!(fixed.date_1, expected.date_1, "{}", name);
+		assert_eq!(marc.isbns.len(), expected.isbns, "{}", name);
+		assert_eq!(
+			marc.additional_material_characteristics.is_some(),
+			expected.has_006,
+			"{}",
+			name
+		);
+	}
+}
+
+#[test]
+fn field_006_round_trips() {
+	// Undefined positions are written back as blanks, so compare what they decode to rather than the bytes.
+	for expected in RECORDS.iter().filter(|expected| expected.has_006) {
+		let record = MarcRef::from_bytes(expected.bytes).unwrap();
+		let field_006 = record.field("006").unwrap();
+		let material = AdditionalMaterialCharacteristics::from_field_006(field_006).unwrap();
+		let encoded = material.to_field_006();
+		assert_eq!(encoded[0], field_006[0], "{}", expected.name);
+		assert_eq!(
+			AdditionalMaterialCharacteristics::from_field_006(&encoded),
+			Some(material),
+			"{}",
+			expected.name
+		);
+	}
+}
+
+#[test]
+fn field_008_stamps_an_equivalent_006() {
+	for expected in RECORDS {
+		let marc = Marc::from_bytes(expected.bytes).unwrap();
+		let fixed = marc.eight.unwrap();
+		let encoded = fixed.to_field_006().unwrap();
+		assert_eq!(
+			AdditionalMaterialCharacteristics::from_field_006(&encoded),
+			fixed.material,
+			"{}",
+			expected.name
+		);
+	}
+}
+
+#[test]
+fn raw_fields_round_trip() {
+	for expected in RECORDS {
+		let record = MarcRef::from_bytes(expected.bytes).unwrap();
+		assert_eq!(record.as_bytes(), expected.bytes, "{}", expected.name);
+		let rebuilt: Vec<u8> = record
+			.entries()
+			.iter()
+			.flat_map(|entry| record.raw_field(entry).to_vec())
+			.collect();
+		let base_address = record.leader().base_address_of_data;
+		assert_eq!(
+			rebuilt,
+			expected.bytes[base_address..expected.bytes.len() - 1],
+			"{}",
+			expected.name
+		);
+		let owned = record.to_owned().unwrap();
+		let direct = Marc::from_bytes(expected.bytes).unwrap();
+		assert_eq!(
+			owned.to_line_mode(),
+			direct.to_line_mode(),
+			"{}",
+			expected.name
+		);
+	}
+}
+
+#[test]
+fn reads_records_from_a_file() {
+	let file: Vec<u8> = RECORDS
+		.iter()
+		.flat_map(|expected| expected.bytes.to_vec())
+		.collect();
+	let control_numbers: Vec<&str> = RECORDS
+		.iter()
+		.map(|expected| expected.control_number)
+		.collect();
+
+	let from_buffer: Vec<String> = MarcRecords::new(&file)
+		.map(|marc| marc.unwrap().control_number)
+		.collect();
+	assert_eq!(from_buffer, control_numbers);
+
+	let from_reader: Vec<String> = MarcReader::new(Cursor::new(&file))
+		.map(|marc| marc.unwrap().control_number)
+		.collect();
+	assert_eq!(from_reader, control_numbers);
+}